@@ -4,15 +4,17 @@ use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::{TcpStream, ToSocketAddrs};
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::spawn;
-use tokio::sync::RwLock;
+use tokio::sync::{OnceCell, RwLock};
 use crate::error::QueryError;
 use crate::event::{DefaultEventHandler, Event, EventHandler};
 use crate::parser::{Command, CommandResponse};
+use crate::permissions::PermissionCatalog;
 
 #[derive(Clone)]
 pub struct QueryClient {
     command_tx: flume::Sender<TSCommand>,
     event_handler: Arc<RwLock<WrappedEventHandler>>,
+    permission_catalog: Arc<OnceCell<PermissionCatalog>>,
 }
 
 impl QueryClient {
@@ -33,6 +35,7 @@ impl QueryClient {
         let client = Self {
             command_tx: command_tx.clone(),
             event_handler: event_handler.clone(),
+            permission_catalog: Arc::new(OnceCell::new()),
         };
 
         spawn(Self::reader_loop(reader, response_tx, client.clone()));
@@ -81,6 +84,13 @@ impl QueryClient {
         self.event_handler.write().await.handler = Arc::new(event_handler);
     }
 
+    /// Returns the permission catalog of the server, fetching it with `permissionlist` on first use
+    pub async fn permission_catalog(&self) -> Result<&PermissionCatalog, QueryError> {
+        self.permission_catalog.get_or_try_init(|| async {
+            Ok(PermissionCatalog::new(self.permission_list().await?))
+        }).await
+    }
+
     async fn read_welcome_message(reader: &mut BufReader<OwnedReadHalf>) -> Result<(), QueryError> {
         let mut buf = Vec::new();
 
@@ -163,6 +173,11 @@ impl QueryClient {
                     client.event_handler.read().await.handler.handle_event(client.clone(), event).await;
                     tmp_buf.clear();
                 } else {
+                    // some commands (e.g. banclient) respond with one entry per line
+                    if !buf.is_empty() {
+                        buf.push(b'|');
+                    }
+
                     buf.extend_from_slice(&tmp_buf);
                     tmp_buf.clear();
                 }
@@ -1,5 +1,11 @@
 use crate::parser::CommandResponse;
 
+/// Returned when a client id does not belong to a connected client
+pub const ERROR_INVALID_CLIENT_ID: i32 = 512;
+
+/// Returned by list commands when there is nothing to list
+pub const ERROR_DATABASE_EMPTY_RESULT_SET: i32 = 1281;
+
 #[derive(Debug)]
 pub enum QueryError {
     ConnectionClosed,
@@ -9,6 +15,7 @@ pub enum QueryError {
     ConnectionFailed(std::io::Error),
     ReadError(std::io::Error),
     FormatError(std::fmt::Error),
+    IoError(std::io::Error),
 
     // response parser
     MissingName { response: String },
@@ -18,6 +25,9 @@ pub enum QueryError {
     MissingArg { key: String },
     ArgTypeError { key: String, value: String, expected_type: String, error: String },
 
+    // request arguments
+    InvalidArgument { key: String, value: String, error: String },
+
     // other
     MalformedEscapeSequence { src: String },
     NotTS3Server,
@@ -25,4 +35,14 @@ pub enum QueryError {
     UnknownEvent { response: String, event: String },
 
     QueryError { id: i32, message: String, response: CommandResponse }
-}
\ No newline at end of file
+}
+
+impl QueryError {
+    /// The id of the permission that caused a server error, if any
+    pub fn failed_permid(&self) -> Option<i32> {
+        match self {
+            QueryError::QueryError { response, .. } => response.args.get("failed_permid")?.parse().ok(),
+            _ => None,
+        }
+    }
+}
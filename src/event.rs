@@ -3,6 +3,7 @@ use log::error;
 use crate::error::QueryError;
 use crate::macros::ts_response;
 use crate::parser::{CommandResponse, Encode};
+use crate::responses::TextMessageTarget;
 use crate::QueryClient;
 
 #[async_trait]
@@ -22,6 +23,7 @@ pub enum EventType {
     TextServer,
     TextChannel,
     TextPrivate,
+    TokenUsed,
 }
 
 impl Encode for EventType {
@@ -32,6 +34,7 @@ impl Encode for EventType {
             EventType::TextServer => buf.push_str("textserver"),
             EventType::TextChannel => buf.push_str("textchannel"),
             EventType::TextPrivate => buf.push_str("textprivate"),
+            EventType::TokenUsed => buf.push_str("tokenused"),
         }
 
         Ok(())
@@ -51,7 +54,7 @@ pub enum Event {
     // ChannelDescriptionChanged(ChannelDescriptionChangeEvent),
     // ChannelPasswordChanged(ChannelPasswordChangeEvent),
     // ServerEdited(ServerEditEvent),
-    // TokenUsed(TokenUseEvent),
+    TokenUsed(TokenUseEvent),
 }
 
 impl Event {
@@ -72,7 +75,7 @@ impl Event {
             // "notifychanneldescriptionchanged" => Event::ChannelDescriptionChanged(ChannelDescriptionChangeEvent::from(&mut response)?),
             // "notifychannelpasswordchanged" => Event::ChannelPasswordChanged(ChannelPasswordChangeEvent::from(&mut response)?),
             // "notifyserveredited" => Event::ServerEdited(ServerEditEvent::from(&mut response)?),
-            // "notifytokenused" => Event::TokenUsed(TokenUseEvent::from(&mut response)?),
+            "notifytokenused" => Event::TokenUsed(TokenUseEvent::from(&mut response)?),
             _ => return Err(QueryError::UnknownEvent {
                 response: response.to_string(),
                 event: event_name.clone()
@@ -94,7 +97,64 @@ ts_response! {
         invoker_id("invokerid"): i32,
         invoker_name("invokername"): String,
         invoker_uid("invokeruid"): String,
-        target_mode("targetmode"): i32,
+        target_mode("targetmode"): TextMessageTarget,
         message("msg"): String,
     }
 }
+
+ts_response! {
+    TokenUseEvent {
+        client_id("clid"): i32,
+        client_database_id("cldbid"): i32,
+        client_uid("cluid"): String,
+        token: String,
+        token_custom_set("tokencustomset"): String,
+        token_id1("token1"): i32,
+        token_id2("token2"): i32,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_text_message() {
+        let response = CommandResponse::decode("notifytextmessage targetmode=1 msg=Hello\\sWorld invokerid=5 invokername=user invokeruid=abc=", true).unwrap();
+
+        match Event::from(response) {
+            Ok(Event::TextMessage(event)) => {
+                assert_eq!(event.target_mode, TextMessageTarget::Client);
+                assert_eq!(event.message, "Hello World");
+                assert_eq!(event.invoker_id, 5);
+                assert_eq!(event.invoker_uid, "abc=");
+            },
+            Ok(event) => panic!("unexpected event {:?}", event),
+            Err(e) => panic!("{:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_decode_token_used() {
+        let response = CommandResponse::decode("notifytokenused clid=7 cldbid=12 cluid=abc= token=xyz tokencustomset token1=6 token2=0", true).unwrap();
+
+        match Event::from(response) {
+            Ok(Event::TokenUsed(event)) => {
+                assert_eq!(event.client_id, 7);
+                assert_eq!(event.client_database_id, 12);
+                assert_eq!(event.token, "xyz");
+                assert_eq!(event.token_custom_set, "");
+                assert_eq!(event.token_id1, 6);
+            },
+            Ok(event) => panic!("unexpected event {:?}", event),
+            Err(e) => panic!("{:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_decode_text_message_invalid_target() {
+        let response = CommandResponse::decode("notifytextmessage targetmode=7 msg=hi invokerid=5 invokername=user invokeruid=abc", true).unwrap();
+
+        assert!(matches!(Event::from(response), Err(QueryError::ArgTypeError { .. })));
+    }
+}
@@ -1,64 +1,63 @@
-/// # TeamSpeak 3 Query Library
-///
-/// ## Example
-/// ```no_run
-/// use ts3_query_api::QueryClient;
-/// use ts3_query_api::error::QueryError;
-///
-/// #[tokio::main]
-/// async fn main() -> Result<(), QueryError> {
-///     let client = QueryClient::connect(("localhost", 10011)).await?;
-///
-///     // Login and select virtual server
-///     client.login("username", "password").await?;
-///     client.use_sid(1).await?;
-///
-///     // ...
-///
-///     Ok(())
-/// }
-/// ```
-///
-/// ```no_run
-/// use ts3_query_api::QueryClient;
-/// use ts3_query_api::error::QueryError;
-/// use ts3_query_api::event::{Event, EventHandler, EventType};
-///
-/// #[derive(Default)]
-/// struct MyHandler;
-///
-/// #[async_trait::async_trait]
-/// impl EventHandler for MyHandler {
-///     async fn handle_event(&self, client: QueryClient, event: Event) {
-///         match event {
-///             Event::TextMessage(event) => {
-///                 println!("Received text message from {}: {}", event.invoker_id, event.message);
-///             }
-///             _ => {}
-///         }
-///     }
-/// }
-///
-/// #[tokio::main]
-/// async fn main() -> Result<(), QueryError> {
-///     let client = QueryClient::connect(("localhost", 10011)).await?;
-///
-///     // Login and select virtual server
-///     client.login("username", "password").await?;
-///     client.use_sid(1).await?;
-///
-///     // Register for server events
-///     client.server_notify_register(EventType::TextChannel, Some(0)).await?;
-///
-///     // Set event handler
-///     client.set_event_handler(MyHandler::default()).await;
-///
-///     // ...
-///
-///     Ok(())
-/// }
-/// ```
-///
+//! # TeamSpeak 3 Query Library
+//!
+//! ## Example
+//! ```no_run
+//! use ts3_query_api::QueryClient;
+//! use ts3_query_api::error::QueryError;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), QueryError> {
+//!     let client = QueryClient::connect(("localhost", 10011)).await?;
+//!
+//!     // Login and select virtual server
+//!     client.login("username", "password").await?;
+//!     client.use_sid(1).await?;
+//!
+//!     // ...
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ```no_run
+//! use ts3_query_api::QueryClient;
+//! use ts3_query_api::error::QueryError;
+//! use ts3_query_api::event::{Event, EventHandler, EventType};
+//!
+//! #[derive(Default)]
+//! struct MyHandler;
+//!
+//! #[async_trait::async_trait]
+//! impl EventHandler for MyHandler {
+//!     async fn handle_event(&self, client: QueryClient, event: Event) {
+//!         match event {
+//!             Event::TextMessage(event) => {
+//!                 println!("Received text message from {}: {}", event.invoker_id, event.message);
+//!             }
+//!             _ => {}
+//!         }
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), QueryError> {
+//!     let client = QueryClient::connect(("localhost", 10011)).await?;
+//!
+//!     // Login and select virtual server
+//!     client.login("username", "password").await?;
+//!     client.use_sid(1).await?;
+//!
+//!     // Register for server events
+//!     client.server_notify_register(EventType::TextChannel, Some(0)).await?;
+//!
+//!     // Set event handler
+//!     client.set_event_handler(MyHandler::default()).await;
+//!
+//!     // ...
+//!
+//!     Ok(())
+//! }
+//! ```
 
 mod client;
 
@@ -70,6 +69,7 @@ pub mod event;
 
 pub mod error;
 pub mod properties;
+pub mod permissions;
 
 mod macros;
 
@@ -97,4 +97,84 @@ mod tests {
             panic!("Failed to get server version\n{:?}", e);
         }
     }
+
+    /// Starts a server that greets like TeamSpeak and answers each command with the next response
+    async fn fake_server(responses: Vec<&'static [u8]>) -> std::net::SocketAddr {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut reader = BufReader::new(reader);
+
+            writer.write_all(b"TS3\n\rWelcome\n\r").await.unwrap();
+
+            for response in responses {
+                let mut line = Vec::new();
+
+                reader.read_until(b'\r', &mut line).await.unwrap();
+                writer.write_all(response).await.unwrap();
+            }
+        });
+
+        addr
+    }
+
+    #[tokio::test]
+    async fn test_multi_line_response() {
+        init();
+
+        let addr = fake_server(vec![b"banid=7\n\rbanid=8\n\rerror id=0 msg=ok\n\r"]).await;
+        let client = QueryClient::connect(addr).await.unwrap();
+        let mut responses = client.send_command_multi_decode(parser::Command::new("test")).await.unwrap();
+
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].get::<i32>("banid").unwrap(), 7);
+        assert_eq!(responses[1].get::<i32>("banid").unwrap(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_client_db_list_stream_short_pages() {
+        use futures::TryStreamExt;
+
+        init();
+
+        let addr = fake_server(vec![
+            b"cldbid=1 client_unique_identifier=a client_nickname=a client_created=0 client_lastconnected=0 client_totalconnections=1 client_description client_lastip|cldbid=2 client_unique_identifier=b client_nickname=b client_created=0 client_lastconnected=0 client_totalconnections=1 client_description client_lastip\n\rerror id=0 msg=ok\n\r",
+            b"cldbid=3 client_unique_identifier=c client_nickname=c client_created=0 client_lastconnected=0 client_totalconnections=1 client_description client_lastip\n\rerror id=0 msg=ok\n\r",
+            b"error id=1281 msg=database\\sempty\\sresult\\sset\n\r",
+        ]).await;
+
+        let client = QueryClient::connect(addr).await.unwrap();
+        let entries: Vec<_> = client.client_db_list_stream(5).try_collect().await.unwrap();
+
+        assert_eq!(entries.iter().map(|entry| entry.cldbid).collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_client_db_list_stream_invalid_page_size() {
+        use futures::TryStreamExt;
+
+        init();
+
+        let addr = fake_server(vec![]).await;
+        let client = QueryClient::connect(addr).await.unwrap();
+        let result: Result<Vec<_>, _> = client.client_db_list_stream(0).try_collect().await;
+
+        assert!(matches!(result, Err(error::QueryError::InvalidArgument { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_empty_list_response() {
+        init();
+
+        let addr = fake_server(vec![b"error id=1281 msg=database\\sempty\\sresult\\sset\n\r"]).await;
+        let client = QueryClient::connect(addr).await.unwrap();
+
+        assert!(client.ban_list().await.unwrap().is_empty());
+    }
 }
@@ -7,6 +7,18 @@ macro_rules! property {
     };
     ($value_name:ident, i32) => {
         PropertyType::Int($value_name)
+    };
+    ($value_name:ident, i64) => {
+        PropertyType::Long($value_name)
+    };
+    ($value_name:ident, u16) => {
+        PropertyType::UShort($value_name)
+    };
+    ($value_name:ident, u64) => {
+        PropertyType::ULong($value_name)
+    };
+    ($value_name:ident, f32) => {
+        PropertyType::Float($value_name)
     }
 }
 
@@ -19,21 +31,33 @@ macro_rules! property_type {
     };
     (i32) => {
         i32
+    };
+    (i64) => {
+        i64
+    };
+    (u16) => {
+        u16
+    };
+    (u64) => {
+        u64
+    };
+    (f32) => {
+        f32
     }
 }
 
 macro_rules! properties {
-    ($type:ident {
+    ($type:ident $(<$lifetime:lifetime>)? {
         $($name:ident: $ty:ident = $value:expr),* $(,)?
     }) => {
         #[allow(dead_code)]
-        pub enum $type<'a> {
+        pub enum $type $(<$lifetime>)? {
             $($name($crate::macros::property_type!($ty))),*
         }
 
         #[allow(dead_code)]
-        impl<'a> $type<'a> {
-            pub fn contents(self) -> (&'static str, PropertyType<'a>) {
+        impl $(<$lifetime>)? $type $(<$lifetime>)? {
+            pub fn contents<'p>(self) -> (&'static str, PropertyType<'p>) where Self: 'p {
                 let name = match self {
                     $( $type::$name { .. } => $value, )*
                 };
@@ -77,8 +101,49 @@ macro_rules! ts_response {
     }
 }
 
+macro_rules! ts_enum {
+    ($type:ident {
+        $($name:ident = $value:expr),* $(,)?
+    }) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum $type {
+            $($name),*
+        }
+
+        impl $crate::parser::Encode for $type {
+            fn encode(&self, buf: &mut String) -> std::fmt::Result {
+                match self {
+                    $( $type::$name => $crate::parser::Encode::encode(&$value, buf), )*
+                }
+            }
+        }
+
+        impl $crate::parser::Decode for $type {
+            fn decode(key: &str, value: String) -> Result<Self, $crate::error::QueryError> {
+                $(
+                    let mut encoded = String::new();
+                    $crate::parser::Encode::encode(&$value, &mut encoded)
+                        .map_err($crate::error::QueryError::FormatError)?;
+
+                    if encoded == value {
+                        return Ok($type::$name);
+                    }
+                )*
+
+                Err($crate::error::QueryError::ArgTypeError {
+                    key: key.to_string(),
+                    value,
+                    expected_type: stringify!($type).to_string(),
+                    error: "Unknown variant".to_string(),
+                })
+            }
+        }
+    }
+}
+
 pub(crate) use property;
 pub(crate) use property_type;
 pub(crate) use properties;
+pub(crate) use ts_enum;
 pub(crate) use ts_response;
 pub(crate) use ts_response_str;
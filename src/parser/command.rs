@@ -173,7 +173,7 @@ macro_rules! impl_simple_encode {
     };
 }
 
-impl_simple_encode!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_simple_encode!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64);
 
 pub struct CommandListBuilder<'a> {
     buf: &'a mut String,
@@ -243,6 +243,20 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_arg_wide_numbers() {
+        let command = Command::new("test")
+            .arg("key", u64::MAX).unwrap()
+            .arg("key2", -18.5f32).unwrap();
+
+        let command: String = command.into();
+
+        assert_eq!(
+            command,
+            "test key=18446744073709551615 key2=-18.5"
+        )
+    }
+
     #[test]
     fn test_arg_list_single() {
         let command = Command::new("test")
@@ -13,11 +13,16 @@ pub struct CommandResponse {
 // getters
 impl CommandResponse {
     pub fn get<D: Decode>(&mut self, key: &str) -> Result<D, QueryError> {
-        let val = self.args.remove(key).ok_or_else(|| QueryError::MissingArg {
-            key: key.to_string(),
-        })?;
+        match self.args.remove(key) {
+            Some(val) => D::decode(key, val),
+            None => D::decode_missing(key),
+        }
+    }
 
-        D::decode(key, val)
+    pub fn get_opt<D: Decode>(&mut self, key: &str) -> Result<Option<D>, QueryError> {
+        self.args.remove(key)
+            .map(|val| D::decode(key, val))
+            .transpose()
     }
 
     // Only for debugging purposes to prevent Drop from logging warnings
@@ -44,6 +49,10 @@ impl CommandResponse {
         let mut args = HashMap::new();
 
         for arg in parts {
+            if arg.is_empty() {
+                continue;
+            }
+
             let mut parts = arg.splitn(2, '=');
             let key = parts.next()
                 .ok_or_else(|| QueryError::MissingKey {
@@ -71,6 +80,10 @@ impl CommandResponse {
     pub fn decode_multi(buf: &str) -> Result<Vec<Self>, QueryError> {
         let mut responses = Vec::new();
 
+        if buf.is_empty() {
+            return Ok(responses);
+        }
+
         for buf in buf.split('|') {
             responses.push(Self::decode(buf, false)?);
         }
@@ -112,6 +125,13 @@ impl Drop for CommandResponse {
 
 pub trait Decode: Sized {
     fn decode(key: &str, value: String) -> Result<Self, QueryError>;
+
+    /// Called instead of [Decode::decode] if the key is not present in the response
+    fn decode_missing(key: &str) -> Result<Self, QueryError> {
+        Err(QueryError::MissingArg {
+            key: key.to_string(),
+        })
+    }
 }
 
 impl Decode for String {
@@ -147,6 +167,21 @@ impl<T: Decode> Decode for Vec<T> {
     }
 }
 
+/// Decodes missing and empty values to `None`
+impl<T: Decode> Decode for Option<T> {
+    fn decode(key: &str, value: String) -> Result<Self, QueryError> {
+        if value.is_empty() {
+            Ok(None)
+        } else {
+            T::decode(key, value).map(Some)
+        }
+    }
+
+    fn decode_missing(_key: &str) -> Result<Self, QueryError> {
+        Ok(None)
+    }
+}
+
 macro_rules! impl_decode {
     ($($type:ident),*) => {
         $(
@@ -165,7 +200,7 @@ macro_rules! impl_decode {
     };
 }
 
-impl_decode!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_decode!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64);
 
 #[cfg(test)]
 mod test {
@@ -250,6 +285,29 @@ mod test {
         assert_eq!(response.args.len(), 0);
     }
 
+    #[test]
+    fn test_decode_option() {
+        let mut response = CommandResponse::decode("test some_integer=69 empty", true).unwrap();
+
+        match response.get::<Option<i32>>("some_integer") {
+            Ok(val) => assert_eq!(val, Some(69)),
+            Err(e) => panic!("{:?}", e),
+        }
+
+        match response.get::<Option<i32>>("empty") {
+            Ok(val) => assert_eq!(val, None),
+            Err(e) => panic!("{:?}", e),
+        }
+
+        match response.get::<Option<i32>>("missing") {
+            Ok(val) => assert_eq!(val, None),
+            Err(e) => panic!("{:?}", e),
+        }
+
+        assert!(matches!(response.get::<i32>("missing"), Err(QueryError::MissingArg { .. })));
+        assert_eq!(response.args.len(), 0);
+    }
+
     #[test]
     fn test_decode_str_without_name() {
         let mut response = CommandResponse::decode("some_string=hello", false).unwrap();
@@ -283,6 +341,38 @@ mod test {
         assert_eq!(response.args.len(), 0);
     }
 
+    #[test]
+    fn test_decode_multi_empty() {
+        let responses = CommandResponse::decode_multi("").unwrap();
+
+        assert_eq!(responses.len(), 0);
+    }
+
+    #[test]
+    fn test_decode_empty_without_name() {
+        let response = CommandResponse::decode("", false).unwrap();
+
+        assert_eq!(response.name, None);
+        assert_eq!(response.args.len(), 0);
+    }
+
+    #[test]
+    fn test_decode_opt() {
+        let mut response = CommandResponse::decode("some_integer=69", false).unwrap();
+
+        match response.get_opt::<i32>("some_integer") {
+            Ok(val) => assert_eq!(val, Some(69)),
+            Err(e) => panic!("{:?}", e),
+        }
+
+        match response.get_opt::<i32>("missing") {
+            Ok(val) => assert_eq!(val, None),
+            Err(e) => panic!("{:?}", e),
+        }
+
+        assert_eq!(response.args.len(), 0);
+    }
+
     #[test]
     fn test_decode_multi() {
         let mut responses = CommandResponse::decode_multi("test1=hi|test2=mom").unwrap();
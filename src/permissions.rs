@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+use crate::error::QueryError;
+use crate::parser::{CommandListBuilder, CommandResponse, EncodeList};
+use crate::responses::{PermissionFindEntry, PermissionGetEntry, PermissionIdEntry, PermissionListEntry, PermissionOverviewEntry};
+
+/// A permission referenced either by its numeric id (`permid`) or by its name (`permsid`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Permission {
+    Id(i32),
+    Sid(String),
+}
+
+impl Permission {
+    pub fn key(&self) -> &'static str {
+        match self {
+            Permission::Id(_) => "permid",
+            Permission::Sid(_) => "permsid",
+        }
+    }
+
+    /// Decodes whichever of `permsid` or `permid` is present in the response
+    pub fn from_response(response: &mut CommandResponse) -> Result<Self, QueryError> {
+        match response.get_opt("permsid")? {
+            Some(sid) => Ok(Permission::Sid(sid)),
+            None => Ok(Permission::Id(response.get("permid")?)),
+        }
+    }
+}
+
+impl From<i32> for Permission {
+    fn from(id: i32) -> Self {
+        Permission::Id(id)
+    }
+}
+
+impl From<&str> for Permission {
+    fn from(sid: &str) -> Self {
+        Permission::Sid(sid.to_owned())
+    }
+}
+
+impl From<String> for Permission {
+    fn from(sid: String) -> Self {
+        Permission::Sid(sid)
+    }
+}
+
+impl EncodeList for Permission {
+    fn encode_list(&self, builder: &mut CommandListBuilder) -> Result<(), QueryError> {
+        match self {
+            Permission::Id(id) => builder.add(self.key(), *id),
+            Permission::Sid(sid) => builder.add_ref(self.key(), sid),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PermissionValue {
+    pub value: i32,
+    /// Only sent by `servergroupaddperm`, the other add commands drop it
+    pub negated: bool,
+    /// Only sent by `servergroupaddperm` and `clientaddperm`, the other add commands drop it
+    pub skip: bool,
+}
+
+impl PermissionValue {
+    pub fn new(value: i32) -> Self {
+        Self {
+            value,
+            negated: false,
+            skip: false,
+        }
+    }
+
+    pub fn negated(mut self, negated: bool) -> Self {
+        self.negated = negated;
+        self
+    }
+
+    pub fn skip(mut self, skip: bool) -> Self {
+        self.skip = skip;
+        self
+    }
+}
+
+impl From<i32> for PermissionValue {
+    fn from(value: i32) -> Self {
+        Self::new(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionEntry {
+    pub permission: Permission,
+    pub value: PermissionValue,
+}
+
+impl PermissionEntry {
+    pub fn new<P: Into<Permission>, V: Into<PermissionValue>>(permission: P, value: V) -> Self {
+        Self {
+            permission: permission.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn from(response: &mut CommandResponse) -> Result<Self, QueryError> {
+        Ok(Self {
+            permission: Permission::from_response(response)?,
+            value: PermissionValue {
+                value: response.get("permvalue")?,
+                negated: response.get_opt("permnegated")?.unwrap_or(false),
+                skip: response.get_opt("permskip")?.unwrap_or(false),
+            },
+        })
+    }
+}
+
+/// Encodes a [PermissionEntry] with only the flags the target command understands
+pub(crate) struct PermissionEntryArg<'a> {
+    pub entry: &'a PermissionEntry,
+    pub negated: bool,
+    pub skip: bool,
+}
+
+impl<'a> PermissionEntryArg<'a> {
+    pub fn list(entries: &'a [PermissionEntry], negated: bool, skip: bool) -> Vec<Self> {
+        entries.iter()
+            .map(|entry| Self { entry, negated, skip })
+            .collect()
+    }
+}
+
+impl EncodeList for PermissionEntryArg<'_> {
+    fn encode_list(&self, builder: &mut CommandListBuilder) -> Result<(), QueryError> {
+        self.entry.permission.encode_list(builder)?;
+        builder.add("permvalue", self.entry.value.value)?;
+
+        if self.negated {
+            builder.add("permnegated", self.entry.value.negated)?;
+        }
+
+        if self.skip {
+            builder.add("permskip", self.entry.value.skip)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionInfo {
+    pub id: i32,
+    pub sid: String,
+    pub description: String,
+}
+
+/// Maps permission ids to their names and descriptions, built from `permissionlist`
+#[derive(Debug, Default)]
+pub struct PermissionCatalog {
+    by_id: HashMap<i32, PermissionInfo>,
+    by_sid: HashMap<String, i32>,
+}
+
+impl PermissionCatalog {
+    pub fn new(entries: Vec<PermissionListEntry>) -> Self {
+        let mut catalog = Self::default();
+
+        for entry in entries {
+            catalog.by_sid.insert(entry.permname.clone(), entry.permid);
+            catalog.by_id.insert(entry.permid, PermissionInfo {
+                id: entry.permid,
+                sid: entry.permname,
+                description: entry.permdesc,
+            });
+        }
+
+        catalog
+    }
+
+    pub fn get(&self, permission: &Permission) -> Option<&PermissionInfo> {
+        match permission {
+            Permission::Id(id) => self.by_id.get(id),
+            Permission::Sid(sid) => self.by_sid.get(sid).and_then(|id| self.by_id.get(id)),
+        }
+    }
+
+    pub fn id(&self, permission: &Permission) -> Option<i32> {
+        self.get(permission).map(|info| info.id)
+    }
+
+    pub fn sid(&self, permission: &Permission) -> Option<&str> {
+        self.get(permission).map(|info| info.sid.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
+    }
+
+    /// Formats an error, naming the permission the server reported as missing
+    pub fn describe_error(&self, error: &QueryError) -> String {
+        let QueryError::QueryError { id, message, .. } = error else {
+            return format!("{:?}", error);
+        };
+
+        match error.failed_permid() {
+            Some(permid) => match self.sid(&Permission::Id(permid)) {
+                Some(sid) => format!("{} (error id {}, failed permission {} [{}])", message, id, sid, permid),
+                None => format!("{} (error id {}, failed permission {})", message, id, permid),
+            },
+            None => format!("{} (error id {})", message, id),
+        }
+    }
+}
+
+/// Implemented by responses that reference a permission
+pub trait PermissionRef {
+    fn permission(&self) -> Permission;
+
+    fn permission_info<'a>(&self, catalog: &'a PermissionCatalog) -> Option<&'a PermissionInfo> {
+        catalog.get(&self.permission())
+    }
+
+    fn permission_id(&self, catalog: &PermissionCatalog) -> Option<i32> {
+        self.permission_info(catalog).map(|info| info.id)
+    }
+
+    fn permission_sid<'a>(&self, catalog: &'a PermissionCatalog) -> Option<&'a str> {
+        self.permission_info(catalog).map(|info| info.sid.as_str())
+    }
+}
+
+impl PermissionRef for PermissionEntry {
+    fn permission(&self) -> Permission {
+        self.permission.clone()
+    }
+}
+
+impl PermissionRef for PermissionListEntry {
+    fn permission(&self) -> Permission {
+        Permission::Id(self.permid)
+    }
+}
+
+impl PermissionRef for PermissionIdEntry {
+    fn permission(&self) -> Permission {
+        Permission::Id(self.permid)
+    }
+}
+
+impl PermissionRef for PermissionGetEntry {
+    fn permission(&self) -> Permission {
+        Permission::Id(self.permid)
+    }
+}
+
+impl PermissionRef for PermissionFindEntry {
+    fn permission(&self) -> Permission {
+        Permission::Id(self.permid)
+    }
+}
+
+impl PermissionRef for PermissionOverviewEntry {
+    fn permission(&self) -> Permission {
+        Permission::Id(self.permid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::Command;
+
+    #[test]
+    fn test_encode_permissions() {
+        let command = Command::new("test")
+            .arg_multi_list(&[Permission::Id(5), Permission::from("i_client_move_power")]).unwrap();
+
+        let command: String = command.into();
+
+        assert_eq!(
+            command,
+            "test permid=5|permsid=i_client_move_power"
+        )
+    }
+
+    #[test]
+    fn test_encode_permission_entries() {
+        let entries = [
+            PermissionEntry::new(5, 75),
+            PermissionEntry::new("b_client_skip_channelgroup_permissions", PermissionValue::new(1).negated(true).skip(true)),
+        ];
+
+        let command = Command::new("test")
+            .arg("sgid", 6).unwrap()
+            .arg_multi_list(&PermissionEntryArg::list(&entries, true, true)).unwrap();
+
+        let command: String = command.into();
+
+        assert_eq!(
+            command,
+            "test sgid=6 permid=5 permvalue=75 permnegated=0 permskip=0|permsid=b_client_skip_channelgroup_permissions permvalue=1 permnegated=1 permskip=1"
+        );
+
+        let command = Command::new("test")
+            .arg("cid", 1).unwrap()
+            .arg_multi_list(&PermissionEntryArg::list(&entries, false, false)).unwrap();
+
+        let command: String = command.into();
+
+        assert_eq!(
+            command,
+            "test cid=1 permid=5 permvalue=75|permsid=b_client_skip_channelgroup_permissions permvalue=1"
+        );
+    }
+
+    #[test]
+    fn test_decode_permission_entries() {
+        let mut responses = CommandResponse::decode_multi("permid=5 permvalue=75 permnegated=0 permskip=1|permsid=i_channel_needed_join_power permvalue=50 permnegated=1 permskip=0").unwrap();
+
+        let entry = PermissionEntry::from(&mut responses[0]).unwrap();
+
+        assert_eq!(entry, PermissionEntry::new(5, PermissionValue::new(75).skip(true)));
+
+        let entry = PermissionEntry::from(&mut responses[1]).unwrap();
+
+        assert_eq!(entry, PermissionEntry::new("i_channel_needed_join_power", PermissionValue::new(50).negated(true)));
+    }
+
+    fn catalog() -> PermissionCatalog {
+        let mut entries = Vec::new();
+
+        for mut response in CommandResponse::decode_multi("permid=1 permname=b_serverinstance_help_view permdesc=Retrieve\\sinformation\\sabout\\sServerQuery\\scommands|permid=151 permname=i_client_move_power permdesc=Client\\smove\\spower").unwrap() {
+            entries.push(PermissionListEntry::from(&mut response).unwrap());
+        }
+
+        PermissionCatalog::new(entries)
+    }
+
+    #[test]
+    fn test_catalog_lookup() {
+        let catalog = catalog();
+
+        assert_eq!(catalog.len(), 2);
+        assert_eq!(catalog.sid(&Permission::Id(151)), Some("i_client_move_power"));
+        assert_eq!(catalog.id(&Permission::from("b_serverinstance_help_view")), Some(1));
+        assert_eq!(catalog.get(&Permission::Id(151)).unwrap().description, "Client move power");
+        assert_eq!(catalog.get(&Permission::Id(2)), None);
+
+        let entry = PermissionEntry::new(151, 50);
+
+        assert_eq!(entry.permission_sid(&catalog), Some("i_client_move_power"));
+    }
+
+    #[test]
+    fn test_catalog_describe_error() {
+        let catalog = catalog();
+
+        let error = QueryError::QueryError {
+            id: 2568,
+            message: "insufficient client permissions".to_string(),
+            response: CommandResponse::decode("failed_permid=151", false).unwrap(),
+        };
+
+        assert_eq!(
+            catalog.describe_error(&error),
+            "insufficient client permissions (error id 2568, failed permission i_client_move_power [151])"
+        );
+    }
+}
@@ -4,6 +4,10 @@ use crate::parser::Encode;
 pub enum PropertyType<'a> {
     Str(&'a str),
     Int(i32),
+    Long(i64),
+    UShort(u16),
+    ULong(u64),
+    Float(f32),
     Bool(bool),
 }
 
@@ -12,13 +16,17 @@ impl Encode for PropertyType<'_> {
         match self {
             PropertyType::Str(val) => val.encode(buf),
             PropertyType::Int(val) => val.encode(buf),
+            PropertyType::Long(val) => val.encode(buf),
+            PropertyType::UShort(val) => val.encode(buf),
+            PropertyType::ULong(val) => val.encode(buf),
+            PropertyType::Float(val) => val.encode(buf),
             PropertyType::Bool(val) => val.encode(buf),
         }
     }
 }
 
 properties! {
-    ChannelProperty {
+    ChannelProperty<'a> {
         Cpid: i32 = "cpid",
 
         Name: str = "channel_name",
@@ -53,4 +61,118 @@ properties! {
         BannerUrl: str = "channel_banner_gfx_url",
         BannerMode: i32 = "channel_banner_mode"
     }
-}
\ No newline at end of file
+}
+
+properties! {
+    ClientProperty<'a> {
+        Description: str = "client_description",
+        IsTalker: bool = "client_is_talker",
+        NicknamePhonetic: str = "client_nickname_phonetic",
+        IconId: i32 = "client_icon_id"
+    }
+}
+
+properties! {
+    ClientDbProperty<'a> {
+        Description: str = "client_description"
+    }
+}
+
+properties! {
+    QueryClientProperty<'a> {
+        Nickname: str = "client_nickname",
+        NicknamePhonetic: str = "client_nickname_phonetic",
+        Away: bool = "client_away",
+        AwayMessage: str = "client_away_message",
+        IsChannelCommander: bool = "client_is_channel_commander",
+        IconId: i32 = "client_icon_id"
+    }
+}
+
+properties! {
+    VirtualServerProperty<'a> {
+        Name: str = "virtualserver_name",
+        NamePhonetic: str = "virtualserver_name_phonetic",
+        Nickname: str = "virtualserver_nickname",
+        WelcomeMessage: str = "virtualserver_welcomemessage",
+        Password: str = "virtualserver_password",
+        MachineId: str = "virtualserver_machine_id",
+
+        Port: u16 = "virtualserver_port",
+        AutoStart: bool = "virtualserver_autostart",
+
+        MaxClients: i32 = "virtualserver_maxclients",
+        ReservedSlots: i32 = "virtualserver_reserved_slots",
+
+        HostMessage: str = "virtualserver_hostmessage",
+        HostMessageMode: i32 = "virtualserver_hostmessage_mode",
+
+        DefaultServerGroup: i32 = "virtualserver_default_server_group",
+        DefaultChannelGroup: i32 = "virtualserver_default_channel_group",
+        DefaultChannelAdminGroup: i32 = "virtualserver_default_channel_admin_group",
+
+        MaxDownloadTotalBandwidth: u64 = "virtualserver_max_download_total_bandwidth",
+        MaxUploadTotalBandwidth: u64 = "virtualserver_max_upload_total_bandwidth",
+        DownloadQuota: u64 = "virtualserver_download_quota",
+        UploadQuota: u64 = "virtualserver_upload_quota",
+
+        HostBannerUrl: str = "virtualserver_hostbanner_url",
+        HostBannerGfxUrl: str = "virtualserver_hostbanner_gfx_url",
+        HostBannerGfxInterval: i32 = "virtualserver_hostbanner_gfx_interval",
+        HostBannerMode: i32 = "virtualserver_hostbanner_mode",
+
+        HostButtonTooltip: str = "virtualserver_hostbutton_tooltip",
+        HostButtonUrl: str = "virtualserver_hostbutton_url",
+        HostButtonGfxUrl: str = "virtualserver_hostbutton_gfx_url",
+
+        ComplainAutobanCount: i32 = "virtualserver_complain_autoban_count",
+        ComplainAutobanTime: i32 = "virtualserver_complain_autoban_time",
+        ComplainRemoveTime: i32 = "virtualserver_complain_remove_time",
+
+        MinClientsInChannelBeforeForcedSilence: i32 = "virtualserver_min_clients_in_channel_before_forced_silence",
+        PrioritySpeakerDimmModificator: f32 = "virtualserver_priority_speaker_dimm_modificator",
+
+        AntifloodPointsTickReduce: i32 = "virtualserver_antiflood_points_tick_reduce",
+        AntifloodPointsNeededCommandBlock: i32 = "virtualserver_antiflood_points_needed_command_block",
+        AntifloodPointsNeededIpBlock: i32 = "virtualserver_antiflood_points_needed_ip_block",
+        AntifloodPointsNeededPluginBlock: i32 = "virtualserver_antiflood_points_needed_plugin_block",
+
+        NeededIdentitySecurityLevel: i32 = "virtualserver_needed_identity_security_level",
+        MinClientVersion: i64 = "virtualserver_min_client_version",
+        MinAndroidVersion: i64 = "virtualserver_min_android_version",
+        MinIosVersion: i64 = "virtualserver_min_ios_version",
+
+        LogClient: bool = "virtualserver_log_client",
+        LogQuery: bool = "virtualserver_log_query",
+        LogChannel: bool = "virtualserver_log_channel",
+        LogPermissions: bool = "virtualserver_log_permissions",
+        LogServer: bool = "virtualserver_log_server",
+        LogFileTransfer: bool = "virtualserver_log_filetransfer",
+
+        IconId: i32 = "virtualserver_icon_id",
+        WeblistEnabled: bool = "virtualserver_weblist_enabled",
+        CodecEncryptionMode: i32 = "virtualserver_codec_encryption_mode",
+        ChannelTempDeleteDelayDefault: i32 = "virtualserver_channel_temp_delete_delay_default"
+    }
+}
+
+properties! {
+    InstanceProperty {
+        GuestServerQueryGroup: i32 = "serverinstance_guest_serverquery_group",
+
+        TemplateServerAdminGroup: i32 = "serverinstance_template_serveradmin_group",
+        TemplateServerDefaultGroup: i32 = "serverinstance_template_serverdefault_group",
+        TemplateChannelAdminGroup: i32 = "serverinstance_template_channeladmin_group",
+        TemplateChannelDefaultGroup: i32 = "serverinstance_template_channeldefault_group",
+
+        FileTransferPort: u16 = "serverinstance_filetransfer_port",
+        MaxDownloadTotalBandwidth: u64 = "serverinstance_max_download_total_bandwidth",
+        MaxUploadTotalBandwidth: u64 = "serverinstance_max_upload_total_bandwidth",
+
+        ServerQueryFloodCommands: i32 = "serverinstance_serverquery_flood_commands",
+        ServerQueryFloodTime: i32 = "serverinstance_serverquery_flood_time",
+        ServerQueryBanTime: i32 = "serverinstance_serverquery_ban_time",
+        ServerQueryMaxConnectionsPerIp: i32 = "serverinstance_serverquery_max_connections_per_ip",
+        PendingConnectionsPerIp: i32 = "serverinstance_pending_connections_per_ip"
+    }
+}
@@ -1,74 +1,77 @@
+use std::collections::HashMap;
+use futures::{stream, Stream, TryStreamExt};
 use crate::client::QueryClient;
-use crate::error::QueryError;
+use crate::error::{QueryError, ERROR_DATABASE_EMPTY_RESULT_SET, ERROR_INVALID_CLIENT_ID};
 use crate::event::EventType;
-use crate::responses::{ChannelInfo, ChannelListBannerEntry, ChannelListFlagsEntry, ChannelListDynamicEntry, ChannelListIconEntry, ChannelListEntry, ChannelListLimitsEntry, ChannelListSecondsEmptyEntry, ChannelListTopicEntry, ChannelListVoiceEntry, ClientListAwayEntry, ClientListDynamicEntry, ClientListGroupsEntry, ClientListEntry, ClientListTimesEntry, ClientListUidEntry, ClientListVoiceEntry, Version, ClientListInfoEntry, ClientListCountryEntry, ClientListIpEntry, ClientListIconEntry, ClientListBadgesEntry, ClientInfo, WhoAmI};
-use crate::parser::Command;
-use crate::properties::ChannelProperty;
+use crate::responses::{BanEntry, BanTarget, ChannelInfo, ChannelListBannerEntry, ChannelListFlagsEntry, ChannelListDynamicEntry, ChannelListIconEntry, ChannelListEntry, ChannelListLimitsEntry, ChannelListSecondsEmptyEntry, ChannelListTopicEntry, ChannelListVoiceEntry, ClientListAwayEntry, ClientListDynamicEntry, ClientListGroupsEntry, ClientListEntry, ClientListTimesEntry, ClientListUidEntry, ClientListVoiceEntry, Version, ClientListInfoEntry, ClientListCountryEntry, ClientListIpEntry, ClientListIconEntry, ClientListBadgesEntry, ClientInfo, WhoAmI, GroupType, ServerGroupEntry, ServerGroupClientEntry, ServerGroupsByClientIdEntry, ChannelGroupEntry, ChannelGroupClientEntry, PermissionListEntry, PermissionIdEntry, PermissionGetEntry, PermissionFindEntry, PermissionOverviewEntry, ClientDbList, ClientDbListEntry, ClientDbInfo, ClientIdsEntry, ClientDbIdFromUid, ClientNameEntry, ClientUidFromClid, ClientIdentifier, ClientIdentity, KickReason, TextMessageTarget, OfflineMessage, OfflineMessageContent, Complaint, CustomSearchEntry, TokenType, TokenEntry, ApiKeyScope, ApiKey, ApiKeyEntry, ApiKeyList, QueryLogin, QueryLoginEntry, ServerListEntry, ServerCreated, ServerInfo, HostInfo, InstanceInfo, ServerSnapshot, SnapshotChannelMapping, LogLevel, LogLine, LogView, TempPasswordEntry, ServerConnectionInfo, BindingSubsystem, BindingEntry, ChannelFindEntry, ClientFindEntry};
+use crate::parser::{Command, CommandListBuilder, CommandResponse};
+use crate::permissions::{Permission, PermissionEntry, PermissionEntryArg};
+use crate::properties::{ChannelProperty, ClientProperty, ClientDbProperty, QueryClientProperty, VirtualServerProperty, InstanceProperty};
 
 // TODO:
-// [ ] apikeyadd
-// [ ] apikeydel
-// [ ] apikeylist
-// [ ] banadd
-// [ ] banclient
-// [ ] bandel
-// [ ] bandelall
-// [ ] banlist
-// [ ] bindinglist
+// [X] apikeyadd
+// [X] apikeydel
+// [X] apikeylist
+// [X] banadd
+// [X] banclient
+// [X] bandel
+// [X] bandelall
+// [X] banlist
+// [X] bindinglist
 // [X] channeladdperm
-// [ ] channelclientaddperm
-// [ ] channelclientdelperm
-// [ ] channelclientpermlist
+// [X] channelclientaddperm
+// [X] channelclientdelperm
+// [X] channelclientpermlist
 // [X] channelcreate
 // [X] channeldelete
-// [ ] channeldelperm
+// [X] channeldelperm
 // [ ] channeledit
-// [ ] channelfind
-// [ ] channelgroupadd
-// [ ] channelgroupaddperm
-// [ ] channelgroupclientlist
-// [ ] channelgroupcopy
-// [ ] channelgroupdel
-// [ ] channelgroupdelperm
-// [ ] channelgrouplist
-// [ ] channelgrouppermlist
-// [ ] channelgrouprename
+// [X] channelfind
+// [X] channelgroupadd
+// [X] channelgroupaddperm
+// [X] channelgroupclientlist
+// [X] channelgroupcopy
+// [X] channelgroupdel
+// [X] channelgroupdelperm
+// [X] channelgrouplist
+// [X] channelgrouppermlist
+// [X] channelgrouprename
 // [X] channelinfo
 // [X] channellist
-// [ ] channelmove
-// [ ] channelpermlist
-// [ ] clientaddperm
+// [X] channelmove
+// [X] channelpermlist
+// [X] clientaddperm
 // [ ] clientaddservergroup
-// [ ] clientdbdelete
-// [ ] clientdbedit
-// [ ] clientdbfind
-// [ ] clientdbinfo
-// [ ] clientdblist
-// [ ] clientdelperm
+// [X] clientdbdelete
+// [X] clientdbedit
+// [X] clientdbfind
+// [X] clientdbinfo
+// [X] clientdblist
+// [X] clientdelperm
 // [ ] clientdelservergroup
-// [ ] clientedit
-// [ ] clientfind
-// [ ] clientgetdbidfromuid
-// [ ] clientgetids
-// [ ] clientgetnamefromdbid
-// [ ] clientgetnamefromuid
-// [ ] clientgetuidfromclid
+// [X] clientedit
+// [X] clientfind
+// [X] clientgetdbidfromuid
+// [X] clientgetids
+// [X] clientgetnamefromdbid
+// [X] clientgetnamefromuid
+// [X] clientgetuidfromclid
 // [X] clientinfo
-// [ ] clientkick
+// [X] clientkick
 // [X] clientlist
 // [X] clientmove
-// [ ] clientpermlist
-// [ ] clientpoke
-// [ ] clientsetserverquerylogin
-// [ ] clientupdate
-// [ ] complainadd
-// [ ] complaindel
-// [ ] complaindelall
-// [ ] complainlist
-// [ ] customdelete
-// [ ] custominfo
-// [ ] customsearch
-// [ ] customset
+// [X] clientpermlist
+// [X] clientpoke
+// [X] clientsetserverquerylogin
+// [X] clientupdate
+// [X] complainadd
+// [X] complaindel
+// [X] complaindelall
+// [X] complainlist
+// [X] customdelete
+// [X] custominfo
+// [X] customsearch
+// [X] customset
 // [ ] ftcreatedir
 // [ ] ftdeletefile
 // [ ] ftgetfileinfo
@@ -78,71 +81,71 @@ use crate::properties::ChannelProperty;
 // [ ] ftlist
 // [ ] ftrenamefile
 // [ ] ftstop
-// [ ] gm
+// [X] gm
 // [ ] help
-// [ ] hostinfo
-// [ ] instanceedit
-// [ ] instanceinfo
-// [ ] logadd
+// [X] hostinfo
+// [X] instanceedit
+// [X] instanceinfo
+// [X] logadd
 // [X] login
 // [X] logout
-// [ ] logview
-// [ ] messageadd
-// [ ] messagedel
-// [ ] messageget
-// [ ] messagelist
-// [ ] messageupdateflag
-// [ ] permfind
-// [ ] permget
-// [ ] permidgetbyname
-// [ ] permissionlist
-// [ ] permoverview
-// [ ] permreset
-// [ ] privilegekeyadd
-// [ ] privilegekeydelete
-// [ ] privilegekeylist
-// [ ] privilegekeyuse
-// [ ] queryloginadd
-// [ ] querylogindel
-// [ ] queryloginlist
+// [X] logview
+// [X] messageadd
+// [X] messagedel
+// [X] messageget
+// [X] messagelist
+// [X] messageupdateflag
+// [X] permfind
+// [X] permget
+// [X] permidgetbyname
+// [X] permissionlist
+// [X] permoverview
+// [X] permreset
+// [X] privilegekeyadd
+// [X] privilegekeydelete
+// [X] privilegekeylist
+// [X] privilegekeyuse
+// [X] queryloginadd
+// [X] querylogindel
+// [X] queryloginlist
 // [X] quit
-// [ ] sendtextmessage
-// [ ] servercreate
-// [ ] serverdelete
-// [ ] serveredit
-// [ ] servergroupadd
-// [ ] servergroupaddclient
-// [ ] servergroupaddperm
+// [X] sendtextmessage
+// [X] servercreate
+// [X] serverdelete
+// [X] serveredit
+// [X] servergroupadd
+// [X] servergroupaddclient
+// [X] servergroupaddperm
 // [ ] servergroupautoaddperm
 // [ ] servergroupautodelperm
-// [ ] servergroupclientlist
-// [ ] servergroupcopy
-// [ ] servergroupdel
-// [ ] servergroupdelclient
-// [ ] servergroupdelperm
-// [ ] servergrouplist
-// [ ] servergrouppermlist
-// [ ] servergrouprename
-// [ ] servergroupsbyclientid
-// [ ] serveridgetbyport
-// [ ] serverinfo
-// [ ] serverlist
+// [X] servergroupclientlist
+// [X] servergroupcopy
+// [X] servergroupdel
+// [X] servergroupdelclient
+// [X] servergroupdelperm
+// [X] servergrouplist
+// [X] servergrouppermlist
+// [X] servergrouprename
+// [X] servergroupsbyclientid
+// [X] serveridgetbyport
+// [X] serverinfo
+// [X] serverlist
 // [X] servernotifyregister
 // [ ] servernotifyunregister
-// [ ] serverprocessstop
-// [ ] serverrequestconnectioninfo
-// [ ] serversnapshotcreate
-// [ ] serversnapshotdeploy
-// [ ] serverstart
-// [ ] serverstop
-// [ ] servertemppasswordadd
-// [ ] servertemppassworddel
-// [ ] servertemppasswordlist
-// [ ] setclientchannelgroup
-// [ ] tokenadd
-// [ ] tokendelete
-// [ ] tokenlist
-// [ ] tokenuse
+// [X] serverprocessstop
+// [X] serverrequestconnectioninfo
+// [X] serversnapshotcreate
+// [X] serversnapshotdeploy
+// [X] serverstart
+// [X] serverstop
+// [X] servertemppasswordadd
+// [X] servertemppassworddel
+// [X] servertemppasswordlist
+// [X] setclientchannelgroup
+// [X] tokenadd
+// [X] tokendelete
+// [X] tokenlist
+// [X] tokenuse
 // [X] use
 // [X] version
 // [X] whoami
@@ -233,7 +236,7 @@ impl QueryClient {
 
         let mut channels = Vec::new();
 
-        for mut response in self.send_command_multi_decode(command).await? {
+        for mut response in self.send_command_list(command).await? {
             let base = ChannelListEntry::from(&mut response)?;
             let topic = if topic { Some(ChannelListTopicEntry::from(&mut response)?) } else { None };
             let flags = if flags { Some(ChannelListFlagsEntry::from(&mut response)?) } else { None };
@@ -266,7 +269,7 @@ impl QueryClient {
         let command = Command::new("channellist");
         let mut channels = Vec::new();
 
-        for mut response in self.send_command_multi_decode(command).await? {
+        for mut response in self.send_command_list(command).await? {
             channels.push(ChannelListEntry::from(&mut response)?);
         }
 
@@ -310,7 +313,7 @@ impl QueryClient {
 
         let mut clients = Vec::new();
 
-        for mut response in self.send_command_multi_decode(command).await? {
+        for mut response in self.send_command_list(command).await? {
             let base = ClientListEntry::from(&mut response)?;
             let uid = if uid { Some(ClientListUidEntry::from(&mut response)?) } else { None };
             let away = if away { Some(ClientListAwayEntry::from(&mut response)?) } else { None };
@@ -349,7 +352,7 @@ impl QueryClient {
         let command = Command::new("clientlist");
         let mut clients = Vec::new();
 
-        for mut response in self.send_command_multi_decode(command).await? {
+        for mut response in self.send_command_list(command).await? {
             clients.push(ClientListEntry::from(&mut response)?);
         }
 
@@ -382,7 +385,7 @@ impl QueryClient {
         &self,
         name: &str,
         properties: Vec<ChannelProperty<'_>>
-    ) -> Result<(), QueryError> {
+    ) -> Result<i32, QueryError> {
         let mut command = Command::new("channelcreate")
             .arg("channel_name", name)?;
 
@@ -392,9 +395,9 @@ impl QueryClient {
             command = command.arg(key, value)?;
         }
 
-        self.send_command(command).await?;
+        let mut response = self.send_command_decode(command).await?;
 
-        Ok(())
+        response.get("cid")
     }
 
     pub async fn client_move(
@@ -447,49 +450,1631 @@ impl QueryClient {
         Ok(())
     }
 
-    pub async fn channel_add_perm_id(
+    pub async fn server_notify_register(
+        &self,
+        event: EventType,
+        channel_id: Option<i32>,
+    ) -> Result<(), QueryError> {
+        let command = Command::new("servernotifyregister")
+            .arg("event", event)?
+            .arg_opt("id", channel_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn ban_add(
+        &self,
+        target: BanTarget<'_>,
+        time: Option<i32>,
+        reason: Option<&str>,
+        last_nickname: Option<&str>,
+    ) -> Result<i32, QueryError> {
+        let command = Command::new("banadd")
+            .arg(target.key(), target.value())?
+            .arg_opt("time", time)?
+            .arg_opt("banreason", reason)?
+            .arg_opt("lastnickname", last_nickname)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        response.get("banid")
+    }
+
+    /// Bans a connected client and returns the ids of all ban rules created for it
+    pub async fn ban_client(
+        &self,
+        client_id: i32,
+        time: Option<i32>,
+        reason: Option<&str>,
+    ) -> Result<Vec<i32>, QueryError> {
+        let command = Command::new("banclient")
+            .arg("clid", client_id)?
+            .arg_opt("time", time)?
+            .arg_opt("banreason", reason)?;
+
+        let mut ban_ids = Vec::new();
+
+        for mut response in self.send_command_multi_decode(command).await? {
+            ban_ids.push(response.get("banid")?);
+        }
+
+        Ok(ban_ids)
+    }
+
+    pub async fn ban_delete(&self, ban_id: i32) -> Result<(), QueryError> {
+        let command = Command::new("bandel")
+            .arg("banid", ban_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn ban_delete_all(&self) -> Result<(), QueryError> {
+        let command = Command::new("bandelall");
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn ban_list(&self) -> Result<Vec<BanEntry>, QueryError> {
+        let command = Command::new("banlist");
+        let mut bans = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            bans.push(BanEntry::from(&mut response)?);
+        }
+
+        Ok(bans)
+    }
+
+    pub async fn server_group_add(
+        &self,
+        name: &str,
+        group_type: Option<GroupType>,
+    ) -> Result<i32, QueryError> {
+        let command = Command::new("servergroupadd")
+            .arg("name", name)?
+            .arg_opt("type", group_type)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        response.get("sgid")
+    }
+
+    /// Copies a server group into the target group or, if `target_id` is `None`, into a new group
+    /// whose id is returned
+    pub async fn server_group_copy(
+        &self,
+        source_id: i32,
+        target_id: Option<i32>,
+        name: &str,
+        group_type: GroupType,
+    ) -> Result<Option<i32>, QueryError> {
+        let command = Command::new("servergroupcopy")
+            .arg("ssgid", source_id)?
+            .arg("tsgid", target_id.unwrap_or(0))?
+            .arg("name", name)?
+            .arg("type", group_type)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        response.get_opt("sgid")
+    }
+
+    pub async fn server_group_delete(&self, group_id: i32, force: bool) -> Result<(), QueryError> {
+        let command = Command::new("servergroupdel")
+            .arg("sgid", group_id)?
+            .arg("force", force)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_group_rename(&self, group_id: i32, name: &str) -> Result<(), QueryError> {
+        let command = Command::new("servergrouprename")
+            .arg("sgid", group_id)?
+            .arg("name", name)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_group_list(&self) -> Result<Vec<ServerGroupEntry>, QueryError> {
+        let command = Command::new("servergrouplist");
+        let mut groups = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            groups.push(ServerGroupEntry::from(&mut response)?);
+        }
+
+        Ok(groups)
+    }
+
+    pub async fn server_group_add_client(
+        &self,
+        group_id: i32,
+        client_database_ids: &[i32],
+    ) -> Result<(), QueryError> {
+        let command = Command::new("servergroupaddclient")
+            .arg("sgid", group_id)?
+            .arg_list("cldbid", client_database_ids)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_group_delete_client(
+        &self,
+        group_id: i32,
+        client_database_ids: &[i32],
+    ) -> Result<(), QueryError> {
+        let command = Command::new("servergroupdelclient")
+            .arg("sgid", group_id)?
+            .arg_list("cldbid", client_database_ids)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_group_client_list(&self, group_id: i32) -> Result<Vec<ServerGroupClientEntry>, QueryError> {
+        let command = Command::new("servergroupclientlist")
+            .flag("names", true)
+            .arg("sgid", group_id)?;
+
+        let mut clients = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            clients.push(ServerGroupClientEntry::from(&mut response)?);
+        }
+
+        Ok(clients)
+    }
+
+    pub async fn server_groups_by_client_id(
+        &self,
+        client_database_id: i32,
+    ) -> Result<Vec<ServerGroupsByClientIdEntry>, QueryError> {
+        let command = Command::new("servergroupsbyclientid")
+            .arg("cldbid", client_database_id)?;
+
+        let mut groups = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            groups.push(ServerGroupsByClientIdEntry::from(&mut response)?);
+        }
+
+        Ok(groups)
+    }
+
+    pub async fn channel_group_add(
+        &self,
+        name: &str,
+        group_type: Option<GroupType>,
+    ) -> Result<i32, QueryError> {
+        let command = Command::new("channelgroupadd")
+            .arg("name", name)?
+            .arg_opt("type", group_type)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        response.get("cgid")
+    }
+
+    /// Copies a channel group into the target group or, if `target_id` is `None`, into a new group
+    /// whose id is returned
+    pub async fn channel_group_copy(
+        &self,
+        source_id: i32,
+        target_id: Option<i32>,
+        name: &str,
+        group_type: GroupType,
+    ) -> Result<Option<i32>, QueryError> {
+        let command = Command::new("channelgroupcopy")
+            .arg("scgid", source_id)?
+            .arg("tcgid", target_id.unwrap_or(0))?
+            .arg("name", name)?
+            .arg("type", group_type)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        response.get_opt("cgid")
+    }
+
+    pub async fn channel_group_delete(&self, group_id: i32, force: bool) -> Result<(), QueryError> {
+        let command = Command::new("channelgroupdel")
+            .arg("cgid", group_id)?
+            .arg("force", force)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn channel_group_rename(&self, group_id: i32, name: &str) -> Result<(), QueryError> {
+        let command = Command::new("channelgrouprename")
+            .arg("cgid", group_id)?
+            .arg("name", name)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn channel_group_list(&self) -> Result<Vec<ChannelGroupEntry>, QueryError> {
+        let command = Command::new("channelgrouplist");
+        let mut groups = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            groups.push(ChannelGroupEntry::from(&mut response)?);
+        }
+
+        Ok(groups)
+    }
+
+    pub async fn channel_group_client_list(
+        &self,
+        channel_id: Option<i32>,
+        client_database_id: Option<i32>,
+        group_id: Option<i32>,
+    ) -> Result<Vec<ChannelGroupClientEntry>, QueryError> {
+        let command = Command::new("channelgroupclientlist")
+            .arg_opt("cid", channel_id)?
+            .arg_opt("cldbid", client_database_id)?
+            .arg_opt("cgid", group_id)?;
+
+        let mut clients = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            clients.push(ChannelGroupClientEntry::from(&mut response)?);
+        }
+
+        Ok(clients)
+    }
+
+    pub async fn set_client_channel_group(
+        &self,
+        group_id: i32,
+        channel_id: i32,
+        client_database_id: i32,
+    ) -> Result<(), QueryError> {
+        let command = Command::new("setclientchannelgroup")
+            .arg("cgid", group_id)?
+            .arg("cid", channel_id)?
+            .arg("cldbid", client_database_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    /// Sends a list command, treating an empty result set as an empty list
+    async fn send_command_list(&self, command: Command) -> Result<Vec<CommandResponse>, QueryError> {
+        match self.send_command_multi_decode(command).await {
+            Err(QueryError::QueryError { id: ERROR_DATABASE_EMPTY_RESULT_SET, .. }) => Ok(Vec::new()),
+            result => result,
+        }
+    }
+
+    async fn perm_list(&self, command: Command) -> Result<Vec<PermissionEntry>, QueryError> {
+        let mut permissions = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            // the first entry repeats the target of the list
+            for key in ["cid", "cldbid", "sgid", "cgid"] {
+                response.args.remove(key);
+            }
+
+            permissions.push(PermissionEntry::from(&mut response)?);
+        }
+
+        Ok(permissions)
+    }
+
+    /// Channel permissions have no negated or skip flag, so those parts of the values are not sent
+    pub async fn channel_add_perm(
         &self,
         channel_id: i32,
-        perms_id: i32,
-        perms_value: i32,
+        permissions: &[PermissionEntry],
     ) -> Result<(), QueryError> {
         let command = Command::new("channeladdperm")
             .arg("cid", channel_id)?
-            .arg("permid", perms_id)?
-            .arg("permvalue", perms_value)?;
+            .arg_multi_list(&PermissionEntryArg::list(permissions, false, false))?;
 
         self.send_command(command).await?;
 
         Ok(())
     }
 
-    pub async fn channel_add_perm_sid(
+    pub async fn channel_delete_perm(
         &self,
         channel_id: i32,
-        perms_id: &str,
-        perms_value: i32,
+        permissions: &[Permission],
     ) -> Result<(), QueryError> {
-        let command = Command::new("channeladdperm")
+        let command = Command::new("channeldelperm")
             .arg("cid", channel_id)?
-            .arg("permsid", perms_id)?
-            .arg("permvalue", perms_value)?;
+            .arg_multi_list(permissions)?;
 
         self.send_command(command).await?;
 
         Ok(())
     }
 
-    pub async fn server_notify_register(
+    pub async fn channel_perm_list(
         &self,
-        event: EventType,
-        channel_id: Option<i32>,
+        channel_id: i32,
+        permsid: bool,
+    ) -> Result<Vec<PermissionEntry>, QueryError> {
+        let command = Command::new("channelpermlist")
+            .arg("cid", channel_id)?
+            .flag("permsid", permsid);
+
+        self.perm_list(command).await
+    }
+
+    /// The negated flag of the values is not sent, `clientaddperm` only understands skip
+    pub async fn client_add_perm(
+        &self,
+        client_database_id: i32,
+        permissions: &[PermissionEntry],
     ) -> Result<(), QueryError> {
-        let command = Command::new("servernotifyregister")
-            .arg("event", event)?
-            .arg_opt("id", channel_id)?;
+        let command = Command::new("clientaddperm")
+            .arg("cldbid", client_database_id)?
+            .arg_multi_list(&PermissionEntryArg::list(permissions, false, true))?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn client_delete_perm(
+        &self,
+        client_database_id: i32,
+        permissions: &[Permission],
+    ) -> Result<(), QueryError> {
+        let command = Command::new("clientdelperm")
+            .arg("cldbid", client_database_id)?
+            .arg_multi_list(permissions)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn client_perm_list(
+        &self,
+        client_database_id: i32,
+        permsid: bool,
+    ) -> Result<Vec<PermissionEntry>, QueryError> {
+        let command = Command::new("clientpermlist")
+            .arg("cldbid", client_database_id)?
+            .flag("permsid", permsid);
+
+        self.perm_list(command).await
+    }
+
+    /// The negated and skip flags of the values are ignored, `channelclientaddperm` has neither
+    pub async fn channel_client_add_perm(
+        &self,
+        channel_id: i32,
+        client_database_id: i32,
+        permissions: &[PermissionEntry],
+    ) -> Result<(), QueryError> {
+        let command = Command::new("channelclientaddperm")
+            .arg("cid", channel_id)?
+            .arg("cldbid", client_database_id)?
+            .arg_multi_list(&PermissionEntryArg::list(permissions, false, false))?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn channel_client_delete_perm(
+        &self,
+        channel_id: i32,
+        client_database_id: i32,
+        permissions: &[Permission],
+    ) -> Result<(), QueryError> {
+        let command = Command::new("channelclientdelperm")
+            .arg("cid", channel_id)?
+            .arg("cldbid", client_database_id)?
+            .arg_multi_list(permissions)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn channel_client_perm_list(
+        &self,
+        channel_id: i32,
+        client_database_id: i32,
+        permsid: bool,
+    ) -> Result<Vec<PermissionEntry>, QueryError> {
+        let command = Command::new("channelclientpermlist")
+            .arg("cid", channel_id)?
+            .arg("cldbid", client_database_id)?
+            .flag("permsid", permsid);
+
+        self.perm_list(command).await
+    }
+
+    pub async fn server_group_add_perm(
+        &self,
+        group_id: i32,
+        permissions: &[PermissionEntry],
+    ) -> Result<(), QueryError> {
+        let command = Command::new("servergroupaddperm")
+            .arg("sgid", group_id)?
+            .arg_multi_list(&PermissionEntryArg::list(permissions, true, true))?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_group_delete_perm(
+        &self,
+        group_id: i32,
+        permissions: &[Permission],
+    ) -> Result<(), QueryError> {
+        let command = Command::new("servergroupdelperm")
+            .arg("sgid", group_id)?
+            .arg_multi_list(permissions)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_group_perm_list(
+        &self,
+        group_id: i32,
+        permsid: bool,
+    ) -> Result<Vec<PermissionEntry>, QueryError> {
+        let command = Command::new("servergrouppermlist")
+            .arg("sgid", group_id)?
+            .flag("permsid", permsid);
+
+        self.perm_list(command).await
+    }
+
+    /// The negated and skip flags of the values are ignored, `channelgroupaddperm` has neither
+    pub async fn channel_group_add_perm(
+        &self,
+        group_id: i32,
+        permissions: &[PermissionEntry],
+    ) -> Result<(), QueryError> {
+        let command = Command::new("channelgroupaddperm")
+            .arg("cgid", group_id)?
+            .arg_multi_list(&PermissionEntryArg::list(permissions, false, false))?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn channel_group_delete_perm(
+        &self,
+        group_id: i32,
+        permissions: &[Permission],
+    ) -> Result<(), QueryError> {
+        let command = Command::new("channelgroupdelperm")
+            .arg("cgid", group_id)?
+            .arg_multi_list(permissions)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn channel_group_perm_list(
+        &self,
+        group_id: i32,
+        permsid: bool,
+    ) -> Result<Vec<PermissionEntry>, QueryError> {
+        let command = Command::new("channelgrouppermlist")
+            .arg("cgid", group_id)?
+            .flag("permsid", permsid);
+
+        self.perm_list(command).await
+    }
+
+    pub async fn permission_list(&self) -> Result<Vec<PermissionListEntry>, QueryError> {
+        let command = Command::new("permissionlist");
+        let mut permissions = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            // newer servers interleave permission group markers
+            if !response.args.contains_key("permid") {
+                response.clear();
+                continue;
+            }
+
+            permissions.push(PermissionListEntry::from(&mut response)?);
+        }
+
+        Ok(permissions)
+    }
+
+    pub async fn perm_id_get_by_name(&self, names: &[&str]) -> Result<Vec<PermissionIdEntry>, QueryError> {
+        let command = Command::new("permidgetbyname")
+            .arg_list("permsid", names)?;
+
+        let mut permissions = Vec::new();
+
+        for mut response in self.send_command_multi_decode(command).await? {
+            permissions.push(PermissionIdEntry::from(&mut response)?);
+        }
+
+        Ok(permissions)
+    }
+
+    pub async fn perm_find(&self, permissions: &[Permission]) -> Result<Vec<PermissionFindEntry>, QueryError> {
+        let command = Command::new("permfind")
+            .arg_multi_list(permissions)?;
+
+        let mut entries = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            entries.push(PermissionFindEntry::from(&mut response)?);
+        }
+
+        Ok(entries)
+    }
+
+    pub async fn perm_get(&self, permissions: &[Permission]) -> Result<Vec<PermissionGetEntry>, QueryError> {
+        let command = Command::new("permget")
+            .arg_multi_list(permissions)?;
+
+        let mut entries = Vec::new();
+
+        for mut response in self.send_command_multi_decode(command).await? {
+            entries.push(PermissionGetEntry::from(&mut response)?);
+        }
+
+        Ok(entries)
+    }
+
+    /// Lists where the permissions of a client in a channel come from. If `permission` is `None`,
+    /// all permissions are included.
+    pub async fn perm_overview(
+        &self,
+        channel_id: i32,
+        client_database_id: i32,
+        permission: Option<Permission>,
+    ) -> Result<Vec<PermissionOverviewEntry>, QueryError> {
+        let command = Command::new("permoverview")
+            .arg("cid", channel_id)?
+            .arg("cldbid", client_database_id)?
+            .arg_multi_list(&[permission.unwrap_or(Permission::Id(0))])?;
+
+        let mut entries = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            // the first entry repeats the request arguments
+            for key in ["cid", "cldbid", "permid", "permsid"] {
+                response.args.remove(key);
+            }
+
+            entries.push(PermissionOverviewEntry::from(&mut response)?);
+        }
+
+        Ok(entries)
+    }
+
+    /// Resets all permission settings to their defaults and returns the new admin token
+    pub async fn perm_reset(&self) -> Result<String, QueryError> {
+        let command = Command::new("permreset");
+        let mut response = self.send_command_decode(command).await?;
+
+        response.get("token")
+    }
+
+    pub async fn client_db_list(
+        &self,
+        start: Option<i32>,
+        duration: Option<i32>,
+        count: bool,
+    ) -> Result<ClientDbList, QueryError> {
+        let command = Command::new("clientdblist")
+            .arg_opt("start", start)?
+            .arg_opt("duration", duration)?
+            .flag("count", count);
+
+        let mut entries = Vec::new();
+        let mut total = None;
+
+        for mut response in self.send_command_list(command).await? {
+            if count && total.is_none() {
+                total = response.get_opt("count")?;
+            }
+
+            entries.push(ClientDbListEntry::from(&mut response)?);
+        }
+
+        Ok(ClientDbList {
+            entries,
+            count: total,
+        })
+    }
+
+    /// Pages through the whole client database, requesting `page_size` entries at a time.
+    /// Paging continues until the server returns no more entries, so pages shorter than
+    /// `page_size` (e.g. because the server caps `duration`) don't end the stream early.
+    pub fn client_db_list_stream(
+        &self,
+        page_size: i32,
+    ) -> impl Stream<Item = Result<ClientDbListEntry, QueryError>> {
+        let client = self.clone();
+
+        stream::try_unfold(Some(0), move |start| {
+            let client = client.clone();
+
+            async move {
+                let Some(start) = start else {
+                    return Ok(None);
+                };
+
+                if page_size <= 0 {
+                    return Err(QueryError::InvalidArgument {
+                        key: "page_size".to_string(),
+                        value: page_size.to_string(),
+                        error: "Page size must be positive".to_string(),
+                    });
+                }
+
+                let entries = client.client_db_list(Some(start), Some(page_size), false).await?.entries;
+
+                let next = if entries.is_empty() {
+                    None
+                } else {
+                    Some(start + entries.len() as i32)
+                };
+
+                Ok(Some((stream::iter(entries.into_iter().map(Ok)), next)))
+            }
+        }).try_flatten()
+    }
+
+    pub async fn client_db_info(&self, client_database_id: i32) -> Result<ClientDbInfo, QueryError> {
+        let command = Command::new("clientdbinfo")
+            .arg("cldbid", client_database_id)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        ClientDbInfo::from(&mut response)
+    }
+
+    /// Searches the client database by nickname or, if `uid` is set, by unique identifier.
+    /// Returns the database ids of all matching clients.
+    pub async fn client_db_find(&self, pattern: &str, uid: bool) -> Result<Vec<i32>, QueryError> {
+        let command = Command::new("clientdbfind")
+            .arg("pattern", pattern)?
+            .flag("uid", uid);
+
+        let mut ids = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            ids.push(response.get("cldbid")?);
+        }
+
+        Ok(ids)
+    }
+
+    pub async fn client_db_edit(
+        &self,
+        client_database_id: i32,
+        properties: Vec<ClientDbProperty<'_>>,
+    ) -> Result<(), QueryError> {
+        let mut command = Command::new("clientdbedit")
+            .arg("cldbid", client_database_id)?;
+
+        for property in properties {
+            let (key, value) = property.contents();
+
+            command = command.arg(key, value)?;
+        }
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn client_db_delete(&self, client_database_id: i32) -> Result<(), QueryError> {
+        let command = Command::new("clientdbdelete")
+            .arg("cldbid", client_database_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn client_get_ids(&self, uid: &str) -> Result<Vec<ClientIdsEntry>, QueryError> {
+        let command = Command::new("clientgetids")
+            .arg("cluid", uid)?;
+
+        let mut clients = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            clients.push(ClientIdsEntry::from(&mut response)?);
+        }
+
+        Ok(clients)
+    }
+
+    pub async fn client_get_db_id_from_uid(&self, uid: &str) -> Result<ClientDbIdFromUid, QueryError> {
+        let command = Command::new("clientgetdbidfromuid")
+            .arg("cluid", uid)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        ClientDbIdFromUid::from(&mut response)
+    }
+
+    pub async fn client_get_name_from_db_id(&self, client_database_id: i32) -> Result<ClientNameEntry, QueryError> {
+        let command = Command::new("clientgetnamefromdbid")
+            .arg("cldbid", client_database_id)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        ClientNameEntry::from(&mut response)
+    }
+
+    pub async fn client_get_name_from_uid(&self, uid: &str) -> Result<ClientNameEntry, QueryError> {
+        let command = Command::new("clientgetnamefromuid")
+            .arg("cluid", uid)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        ClientNameEntry::from(&mut response)
+    }
+
+    pub async fn client_get_uid_from_clid(&self, client_id: i32) -> Result<ClientUidFromClid, QueryError> {
+        let command = Command::new("clientgetuidfromclid")
+            .arg("clid", client_id)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        ClientUidFromClid::from(&mut response)
+    }
+
+    /// Resolves any kind of client identifier into the full identity of the client.
+    /// Returns `None` if no such client exists. Nicknames must match exactly.
+    pub async fn resolve_client(&self, identifier: ClientIdentifier<'_>) -> Result<Option<ClientIdentity>, QueryError> {
+        let result = match identifier {
+            ClientIdentifier::ClientId(client_id) => {
+                match self.client_get_uid_from_clid(client_id).await {
+                    Ok(client) => self.client_get_name_from_uid(&client.cluid).await,
+                    Err(e) => Err(e),
+                }
+            },
+            ClientIdentifier::DatabaseId(database_id) => self.client_get_name_from_db_id(database_id).await,
+            ClientIdentifier::Uid(uid) => self.client_get_name_from_uid(uid).await,
+            ClientIdentifier::Nickname(nickname) => {
+                let database_ids = self.client_db_find(nickname, false).await?;
+
+                let mut found = None;
+
+                for database_id in database_ids {
+                    let client = self.client_get_name_from_db_id(database_id).await?;
+
+                    if client.name == nickname {
+                        found = Some(client);
+                        break;
+                    }
+                }
+
+                let Some(client) = found else {
+                    return Ok(None);
+                };
+
+                Ok(client)
+            },
+        };
+
+        let client = match result {
+            Ok(client) => client,
+            Err(QueryError::QueryError { id: ERROR_DATABASE_EMPTY_RESULT_SET | ERROR_INVALID_CLIENT_ID, .. }) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let client_ids = self.client_get_ids(&client.cluid).await?
+            .into_iter()
+            .map(|entry| entry.clid)
+            .collect();
+
+        Ok(Some(ClientIdentity {
+            database_id: client.cldbid,
+            uid: client.cluid,
+            nickname: client.name,
+            client_ids,
+        }))
+    }
+
+    pub async fn client_kick(
+        &self,
+        client_ids: &[i32],
+        reason: KickReason,
+        message: Option<&str>,
+    ) -> Result<(), QueryError> {
+        let command = Command::new("clientkick")
+            .arg_list("clid", client_ids)?
+            .arg("reasonid", reason)?
+            .arg_opt("reasonmsg", message)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn client_poke(&self, client_id: i32, message: &str) -> Result<(), QueryError> {
+        let command = Command::new("clientpoke")
+            .arg("clid", client_id)?
+            .arg("msg", message)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn client_edit(
+        &self,
+        client_id: i32,
+        properties: Vec<ClientProperty<'_>>,
+    ) -> Result<(), QueryError> {
+        let mut command = Command::new("clientedit")
+            .arg("clid", client_id)?;
+
+        for property in properties {
+            let (key, value) = property.contents();
+
+            command = command.arg(key, value)?;
+        }
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn client_update(&self, properties: Vec<QueryClientProperty<'_>>) -> Result<(), QueryError> {
+        let mut command = Command::new("clientupdate");
+
+        for property in properties {
+            let (key, value) = property.contents();
+
+            command = command.arg(key, value)?;
+        }
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    /// Sends a text message to a client, channel or server. `target` is the id of the client,
+    /// channel or virtual server, depending on `target_mode`.
+    pub async fn send_text_message(
+        &self,
+        target_mode: TextMessageTarget,
+        target: i32,
+        message: &str,
+    ) -> Result<(), QueryError> {
+        let command = Command::new("sendtextmessage")
+            .arg("targetmode", target_mode)?
+            .arg("target", target)?
+            .arg("msg", message)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    /// Sends a text message to all clients on all virtual servers
+    pub async fn gm(&self, message: &str) -> Result<(), QueryError> {
+        let command = Command::new("gm")
+            .arg("msg", message)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn message_add(&self, uid: &str, subject: &str, message: &str) -> Result<(), QueryError> {
+        let command = Command::new("messageadd")
+            .arg("cluid", uid)?
+            .arg("subject", subject)?
+            .arg("message", message)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn message_delete(&self, message_id: i32) -> Result<(), QueryError> {
+        let command = Command::new("messagedel")
+            .arg("msgid", message_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn message_get(&self, message_id: i32) -> Result<OfflineMessageContent, QueryError> {
+        let command = Command::new("messageget")
+            .arg("msgid", message_id)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        OfflineMessageContent::from(&mut response)
+    }
+
+    pub async fn message_list(&self) -> Result<Vec<OfflineMessage>, QueryError> {
+        let command = Command::new("messagelist");
+        let mut messages = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            messages.push(OfflineMessage::from(&mut response)?);
+        }
+
+        Ok(messages)
+    }
+
+    pub async fn message_update_flag(&self, message_id: i32, read: bool) -> Result<(), QueryError> {
+        let command = Command::new("messageupdateflag")
+            .arg("msgid", message_id)?
+            .arg("flag", read)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn complain_add(&self, target_database_id: i32, message: &str) -> Result<(), QueryError> {
+        let command = Command::new("complainadd")
+            .arg("tcldbid", target_database_id)?
+            .arg("message", message)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn complain_delete(&self, target_database_id: i32, from_database_id: i32) -> Result<(), QueryError> {
+        let command = Command::new("complaindel")
+            .arg("tcldbid", target_database_id)?
+            .arg("fcldbid", from_database_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn complain_delete_all(&self, target_database_id: i32) -> Result<(), QueryError> {
+        let command = Command::new("complaindelall")
+            .arg("tcldbid", target_database_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn complain_list(&self, target_database_id: Option<i32>) -> Result<Vec<Complaint>, QueryError> {
+        let command = Command::new("complainlist")
+            .arg_opt("tcldbid", target_database_id)?;
+
+        let mut complaints = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            complaints.push(Complaint::from(&mut response)?);
+        }
+
+        Ok(complaints)
+    }
+
+    pub async fn custom_set(&self, client_database_id: i32, ident: &str, value: &str) -> Result<(), QueryError> {
+        let command = Command::new("customset")
+            .arg("cldbid", client_database_id)?
+            .arg("ident", ident)?
+            .arg("value", value)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn custom_delete(&self, client_database_id: i32, ident: &str) -> Result<(), QueryError> {
+        let command = Command::new("customdelete")
+            .arg("cldbid", client_database_id)?
+            .arg("ident", ident)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    /// Returns all custom properties of a client, mapped from ident to value
+    pub async fn custom_info(&self, client_database_id: i32) -> Result<HashMap<String, String>, QueryError> {
+        let command = Command::new("custominfo")
+            .arg("cldbid", client_database_id)?;
+
+        let mut properties = HashMap::new();
+
+        for mut response in self.send_command_list(command).await? {
+            // the first entry repeats the database id
+            response.args.remove("cldbid");
+
+            properties.insert(response.get("ident")?, response.get("value")?);
+        }
+
+        Ok(properties)
+    }
+
+    /// Searches custom properties by ident and value. `pattern` may contain `%` as a wildcard
+    /// matching any sequence of characters.
+    pub async fn custom_search(&self, ident: &str, pattern: &str) -> Result<Vec<CustomSearchEntry>, QueryError> {
+        let command = Command::new("customsearch")
+            .arg("ident", ident)?
+            .arg("pattern", pattern)?;
+
+        let mut entries = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            entries.push(CustomSearchEntry::from(&mut response)?);
+        }
+
+        Ok(entries)
+    }
+
+    /// Creates a privilege key and returns it. For server group keys `group_id` is the server
+    /// group and `channel_id` is ignored, for channel group keys both have to be set.
+    pub async fn token_add(
+        &self,
+        token_type: TokenType,
+        group_id: i32,
+        channel_id: i32,
+        description: Option<&str>,
+        custom_set: &[(&str, &str)],
+    ) -> Result<String, QueryError> {
+        let mut command = Command::new("privilegekeyadd")
+            .arg("tokentype", token_type)?
+            .arg("tokenid1", group_id)?
+            .arg("tokenid2", channel_id)?
+            .arg_opt("tokendescription", description)?;
+
+        if !custom_set.is_empty() {
+            let mut buf = String::new();
+
+            for (i, (ident, value)) in custom_set.iter().enumerate() {
+                if i > 0 {
+                    buf.push('|');
+                }
+
+                let mut builder = CommandListBuilder::new(&mut buf);
+
+                builder.add("ident", *ident)?;
+                builder.add("value", *value)?;
+            }
+
+            command = command.arg("tokencustomset", buf)?;
+        }
+
+        let mut response = self.send_command_decode(command).await?;
+
+        response.get("token")
+    }
+
+    pub async fn token_list(&self) -> Result<Vec<TokenEntry>, QueryError> {
+        let command = Command::new("privilegekeylist");
+        let mut tokens = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            tokens.push(TokenEntry::from(&mut response)?);
+        }
+
+        Ok(tokens)
+    }
+
+    pub async fn token_delete(&self, token: &str) -> Result<(), QueryError> {
+        let command = Command::new("privilegekeydelete")
+            .arg("token", token)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn token_use(&self, token: &str) -> Result<(), QueryError> {
+        let command = Command::new("privilegekeyuse")
+            .arg("token", token)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    /// Creates an api key and returns it together with its secret. `lifetime` is given in days
+    /// and the key is created for the query client if `client_database_id` is `None`.
+    pub async fn api_key_add(
+        &self,
+        scope: ApiKeyScope,
+        lifetime: Option<i32>,
+        client_database_id: Option<i32>,
+    ) -> Result<ApiKey, QueryError> {
+        let command = Command::new("apikeyadd")
+            .arg("scope", scope)?
+            .arg_opt("lifetime", lifetime)?
+            .arg_opt("cldbid", client_database_id)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        ApiKey::from(&mut response)
+    }
+
+    /// Lists the api keys of a client or, if `client_database_id` is `None`, of all clients
+    pub async fn api_key_list(
+        &self,
+        client_database_id: Option<i32>,
+        start: Option<i32>,
+        duration: Option<i32>,
+        count: bool,
+    ) -> Result<ApiKeyList, QueryError> {
+        let command = Command::new("apikeylist");
+
+        let command = match client_database_id {
+            Some(client_database_id) => command.arg("cldbid", client_database_id)?,
+            None => command.arg("cldbid", "*")?,
+        };
+
+        let command = command
+            .arg_opt("start", start)?
+            .arg_opt("duration", duration)?
+            .flag("count", count);
+
+        let mut entries = Vec::new();
+        let mut total = None;
+
+        for mut response in self.send_command_list(command).await? {
+            if count && total.is_none() {
+                total = response.get_opt("count")?;
+            }
+
+            entries.push(ApiKeyEntry::from(&mut response)?);
+        }
+
+        Ok(ApiKeyList {
+            entries,
+            count: total,
+        })
+    }
+
+    pub async fn api_key_delete(&self, id: i32) -> Result<(), QueryError> {
+        let command = Command::new("apikeydel")
+            .arg("id", id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    /// Creates a query login and returns it together with the generated password
+    pub async fn query_login_add(
+        &self,
+        login_name: &str,
+        client_database_id: Option<i32>,
+    ) -> Result<QueryLogin, QueryError> {
+        let command = Command::new("queryloginadd")
+            .arg("client_login_name", login_name)?
+            .arg_opt("cldbid", client_database_id)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        QueryLogin::from(&mut response)
+    }
+
+    pub async fn query_login_delete(&self, client_database_id: i32) -> Result<(), QueryError> {
+        let command = Command::new("querylogindel")
+            .arg("cldbid", client_database_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn query_login_list(
+        &self,
+        pattern: Option<&str>,
+        start: Option<i32>,
+        duration: Option<i32>,
+    ) -> Result<Vec<QueryLoginEntry>, QueryError> {
+        let command = Command::new("queryloginlist")
+            .arg_opt("pattern", pattern)?
+            .arg_opt("start", start)?
+            .arg_opt("duration", duration)?;
+
+        let mut logins = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            logins.push(QueryLoginEntry::from(&mut response)?);
+        }
+
+        Ok(logins)
+    }
+
+    /// Changes the query login name of the current client and returns the generated password
+    pub async fn client_set_server_query_login(&self, login_name: &str) -> Result<String, QueryError> {
+        let command = Command::new("clientsetserverquerylogin")
+            .arg("client_login_name", login_name)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        response.get("client_login_password")
+    }
+
+    pub async fn server_list(
+        &self,
+        uid: bool,
+        short: bool,
+        all: bool,
+        only_offline: bool,
+    ) -> Result<Vec<ServerListEntry>, QueryError> {
+        let command = Command::new("serverlist")
+            .flag("uid", uid)
+            .flag("short", short)
+            .flag("all", all)
+            .flag("onlyoffline", only_offline);
+
+        let mut servers = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            servers.push(ServerListEntry::from(&mut response)?);
+        }
+
+        Ok(servers)
+    }
+
+    /// Creates and starts a virtual server. Returns its id, port and the initial admin token.
+    pub async fn server_create(
+        &self,
+        name: &str,
+        properties: Vec<VirtualServerProperty<'_>>,
+    ) -> Result<ServerCreated, QueryError> {
+        let mut command = Command::new("servercreate")
+            .arg("virtualserver_name", name)?;
+
+        for property in properties {
+            let (key, value) = property.contents();
+
+            command = command.arg(key, value)?;
+        }
+
+        let mut response = self.send_command_decode(command).await?;
+
+        ServerCreated::from(&mut response)
+    }
+
+    pub async fn server_delete(&self, server_id: i32) -> Result<(), QueryError> {
+        let command = Command::new("serverdelete")
+            .arg("sid", server_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_start(&self, server_id: i32) -> Result<(), QueryError> {
+        let command = Command::new("serverstart")
+            .arg("sid", server_id)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_stop(&self, server_id: i32, reason: Option<&str>) -> Result<(), QueryError> {
+        let command = Command::new("serverstop")
+            .arg("sid", server_id)?
+            .arg_opt("reasonmsg", reason)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_id_get_by_port(&self, port: u16) -> Result<i32, QueryError> {
+        let command = Command::new("serveridgetbyport")
+            .arg("virtualserver_port", port)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        response.get("server_id")
+    }
+
+    /// Stops the entire server instance
+    pub async fn server_process_stop(&self, reason: Option<&str>) -> Result<(), QueryError> {
+        let command = Command::new("serverprocessstop")
+            .arg_opt("reasonmsg", reason)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_info(&self) -> Result<ServerInfo, QueryError> {
+        let command = Command::new("serverinfo");
+        let mut response = self.send_command_decode(command).await?;
+
+        ServerInfo::from(&mut response)
+    }
+
+    pub async fn server_edit(&self, properties: Vec<VirtualServerProperty<'_>>) -> Result<(), QueryError> {
+        let mut command = Command::new("serveredit");
+
+        for property in properties {
+            let (key, value) = property.contents();
+
+            command = command.arg(key, value)?;
+        }
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn host_info(&self) -> Result<HostInfo, QueryError> {
+        let command = Command::new("hostinfo");
+        let mut response = self.send_command_decode(command).await?;
+
+        HostInfo::from(&mut response)
+    }
+
+    pub async fn instance_info(&self) -> Result<InstanceInfo, QueryError> {
+        let command = Command::new("instanceinfo");
+        let mut response = self.send_command_decode(command).await?;
+
+        InstanceInfo::from(&mut response)
+    }
+
+    pub async fn instance_edit(&self, properties: Vec<InstanceProperty>) -> Result<(), QueryError> {
+        let mut command = Command::new("instanceedit");
+
+        for property in properties {
+            let (key, value) = property.contents();
+
+            command = command.arg(key, value)?;
+        }
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_snapshot_create(&self, password: Option<&str>) -> Result<ServerSnapshot, QueryError> {
+        let command = Command::new("serversnapshotcreate")
+            .arg_opt("password", password)?;
+
+        let mut response = self.send_command_decode(command).await?;
+
+        ServerSnapshot::from(&mut response)
+    }
+
+    /// Deploys a snapshot on the selected virtual server. If `mapping` is set, the returned list
+    /// maps the channel ids of the snapshot to the ids of the recreated channels.
+    pub async fn server_snapshot_deploy(
+        &self,
+        snapshot: &ServerSnapshot,
+        password: Option<&str>,
+        mapping: bool,
+        keep_files: bool,
+    ) -> Result<Vec<SnapshotChannelMapping>, QueryError> {
+        let command = Command::new("serversnapshotdeploy")
+            .flag("mapping", mapping)
+            .flag("keepfiles", keep_files)
+            .arg_opt("password", password)?
+            .arg("version", snapshot.version)?
+            .arg_opt("salt", snapshot.salt.as_deref())?
+            .arg_ref("data", &snapshot.data)?;
+
+        let mut mappings = Vec::new();
+
+        for mut response in self.send_command_multi_decode(command).await? {
+            if !mapping {
+                response.clear();
+                continue;
+            }
+
+            // the first entry may also carry the id and port of the virtual server
+            for key in ["sid", "virtualserver_port"] {
+                response.args.remove(key);
+            }
+
+            mappings.push(SnapshotChannelMapping::from(&mut response)?);
+        }
+
+        Ok(mappings)
+    }
+
+    pub async fn log_view(
+        &self,
+        lines: Option<i32>,
+        reverse: bool,
+        instance: bool,
+        begin_pos: Option<i64>,
+    ) -> Result<LogView, QueryError> {
+        let command = Command::new("logview")
+            .arg_opt("lines", lines)?
+            .arg("reverse", reverse)?
+            .arg("instance", instance)?
+            .arg_opt("begin_pos", begin_pos)?;
+
+        let mut last_pos = 0;
+        let mut file_size = 0;
+        let mut entries = Vec::new();
+
+        for (i, mut response) in self.send_command_multi_decode(command).await?.into_iter().enumerate() {
+            if i == 0 {
+                last_pos = response.get("last_pos")?;
+                file_size = response.get("file_size")?;
+            }
+
+            entries.push(response.get("l")?);
+        }
+
+        Ok(LogView {
+            last_pos,
+            file_size,
+            entries,
+        })
+    }
+
+    /// Reads the whole log backwards, starting at its end and requesting `lines` entries at a time
+    pub fn log_view_stream(
+        &self,
+        lines: i32,
+        instance: bool,
+    ) -> impl Stream<Item = Result<LogLine, QueryError>> {
+        let client = self.clone();
+
+        stream::try_unfold(Some(None), move |begin_pos| {
+            let client = client.clone();
+
+            async move {
+                let Some(begin_pos) = begin_pos else {
+                    return Ok(None);
+                };
+
+                let view = client.log_view(Some(lines), true, instance, begin_pos).await?;
+
+                let next = if view.last_pos == 0 || view.entries.is_empty() {
+                    None
+                } else {
+                    Some(Some(view.last_pos))
+                };
+
+                Ok(Some((stream::iter(view.entries.into_iter().map(Ok)), next)))
+            }
+        }).try_flatten()
+    }
+
+    pub async fn log_add(&self, level: LogLevel, message: &str) -> Result<(), QueryError> {
+        let command = Command::new("logadd")
+            .arg("loglevel", level)?
+            .arg("logmsg", message)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    /// Adds a temporary server password valid for `duration` seconds. Clients using it join
+    /// `channel_id` if set, using `channel_password` if that channel is protected.
+    pub async fn server_temp_password_add(
+        &self,
+        password: &str,
+        description: &str,
+        duration: i32,
+        channel_id: Option<i32>,
+        channel_password: Option<&str>,
+    ) -> Result<(), QueryError> {
+        let command = Command::new("servertemppasswordadd")
+            .arg("pw", password)?
+            .arg("desc", description)?
+            .arg("duration", duration)?
+            .arg("tcid", channel_id.unwrap_or(0))?
+            .arg("tcpw", channel_password.unwrap_or(""))?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_temp_password_delete(&self, password: &str) -> Result<(), QueryError> {
+        let command = Command::new("servertemppassworddel")
+            .arg("pw", password)?;
+
+        self.send_command(command).await?;
+
+        Ok(())
+    }
+
+    pub async fn server_temp_password_list(&self) -> Result<Vec<TempPasswordEntry>, QueryError> {
+        let command = Command::new("servertemppasswordlist");
+        let mut passwords = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            passwords.push(TempPasswordEntry::from(&mut response)?);
+        }
+
+        Ok(passwords)
+    }
+
+    pub async fn server_request_connection_info(&self) -> Result<ServerConnectionInfo, QueryError> {
+        let command = Command::new("serverrequestconnectioninfo");
+        let mut response = self.send_command_decode(command).await?;
+
+        ServerConnectionInfo::from(&mut response)
+    }
+
+    /// Lists the addresses the server is bound to. Defaults to the voice subsystem.
+    pub async fn binding_list(&self, subsystem: Option<BindingSubsystem>) -> Result<Vec<BindingEntry>, QueryError> {
+        let command = Command::new("bindinglist")
+            .arg_opt("subsystem", subsystem)?;
+
+        let mut bindings = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            bindings.push(BindingEntry::from(&mut response)?);
+        }
+
+        Ok(bindings)
+    }
+
+    pub async fn channel_find(&self, pattern: &str) -> Result<Vec<ChannelFindEntry>, QueryError> {
+        let command = Command::new("channelfind")
+            .arg("pattern", pattern)?;
+
+        let mut channels = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            channels.push(ChannelFindEntry::from(&mut response)?);
+        }
+
+        Ok(channels)
+    }
+
+    pub async fn client_find(&self, pattern: &str) -> Result<Vec<ClientFindEntry>, QueryError> {
+        let command = Command::new("clientfind")
+            .arg("pattern", pattern)?;
+
+        let mut clients = Vec::new();
+
+        for mut response in self.send_command_list(command).await? {
+            clients.push(ClientFindEntry::from(&mut response)?);
+        }
+
+        Ok(clients)
+    }
+
+    pub async fn channel_move(
+        &self,
+        channel_id: i32,
+        parent_id: i32,
+        order: Option<i32>,
+    ) -> Result<(), QueryError> {
+        let command = Command::new("channelmove")
+            .arg("cid", channel_id)?
+            .arg("cpid", parent_id)?
+            .arg_opt("order", order)?;
 
         self.send_command(command).await?;
 
         Ok(())
     }
-}
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 use crate::error::QueryError;
-use crate::macros::ts_response;
-use crate::parser::Decode;
+use crate::macros::{ts_enum, ts_response};
+use std::path::Path;
+use crate::parser::{CommandListBuilder, CommandResponse, Decode};
 
 // version
 
@@ -369,4 +370,867 @@ ts_response! {
         connection_connected_time: i32,
         connection_client_ip: String
     }
+}
+
+// ban list
+
+ts_response! {
+    BanEntry {
+        banid: i32,
+        ip: String,
+        name: String,
+        uid: String,
+        mytsid: String,
+        lastnickname: String,
+        created: i32,
+        duration: i32,
+        invoker_name("invokername"): String,
+        invoker_database_id("invokercldbid"): i32,
+        invoker_uid("invokeruid"): String,
+        reason: String,
+        enforcements: i32
+    }
+}
+
+/// What a ban added with `banadd` matches connecting clients against
+#[derive(Debug, Clone, Copy)]
+pub enum BanTarget<'a> {
+    Ip(&'a str),
+    Name(&'a str),
+    Uid(&'a str),
+    MyTsId(&'a str),
+}
+
+impl<'a> BanTarget<'a> {
+    pub fn key(&self) -> &'static str {
+        match self {
+            BanTarget::Ip(_) => "ip",
+            BanTarget::Name(_) => "name",
+            BanTarget::Uid(_) => "uid",
+            BanTarget::MyTsId(_) => "mytsid",
+        }
+    }
+
+    pub fn value(&self) -> &'a str {
+        match self {
+            BanTarget::Ip(value)
+            | BanTarget::Name(value)
+            | BanTarget::Uid(value)
+            | BanTarget::MyTsId(value) => value,
+        }
+    }
+}
+
+// server groups
+
+ts_enum! {
+    GroupType {
+        Template = 0,
+        Regular = 1,
+        Query = 2
+    }
+}
+
+ts_response! {
+    ServerGroupEntry {
+        sgid: i32,
+        name: String,
+        group_type("type"): GroupType,
+        icon_id("iconid"): i32,
+        savedb: bool,
+        sortid: i32,
+        namemode: i32,
+        n_modifyp: i32,
+        n_member_addp: i32,
+        n_member_removep: i32
+    }
+}
+
+ts_response! {
+    ServerGroupClientEntry {
+        cldbid: i32,
+        client_nickname: String,
+        client_unique_identifier: String
+    }
+}
+
+ts_response! {
+    ServerGroupsByClientIdEntry {
+        name: String,
+        sgid: i32,
+        cldbid: i32
+    }
+}
+
+// channel groups
+
+ts_response! {
+    ChannelGroupEntry {
+        cgid: i32,
+        name: String,
+        group_type("type"): GroupType,
+        icon_id("iconid"): i32,
+        savedb: bool,
+        sortid: i32,
+        namemode: i32,
+        n_modifyp: i32,
+        n_member_addp: i32,
+        n_member_removep: i32
+    }
+}
+
+ts_response! {
+    ChannelGroupClientEntry {
+        cid: i32,
+        cldbid: i32,
+        cgid: i32
+    }
+}
+
+// permissions
+
+ts_response! {
+    PermissionListEntry {
+        permid: i32,
+        permname: String,
+        permdesc: String
+    }
+}
+
+ts_response! {
+    PermissionIdEntry {
+        permsid: String,
+        permid: i32
+    }
+}
+
+ts_response! {
+    PermissionGetEntry {
+        permsid: String,
+        permid: i32,
+        permvalue: i32
+    }
+}
+
+// the level a permission was assigned on
+ts_enum! {
+    PermissionTier {
+        ServerGroup = 0,
+        Client = 1,
+        Channel = 2,
+        ChannelGroup = 3,
+        ChannelClient = 4
+    }
+}
+
+ts_response! {
+    PermissionFindEntry {
+        tier("t"): PermissionTier,
+        id1: i32,
+        id2: i32,
+        permid("p"): i32
+    }
+}
+
+ts_response! {
+    PermissionOverviewEntry {
+        tier("t"): PermissionTier,
+        id1: i32,
+        id2: i32,
+        permid("p"): i32,
+        value("v"): i32,
+        negated("n"): bool,
+        skip("s"): bool
+    }
+}
+
+// client database
+
+ts_response! {
+    ClientDbListEntry {
+        cldbid: i32,
+        client_unique_identifier: String,
+        client_nickname: String,
+        client_created: i32,
+        client_lastconnected: i32,
+        client_totalconnections: i32,
+        client_description: String,
+        client_lastip: String
+    }
+}
+
+#[derive(Debug)]
+pub struct ClientDbList {
+    pub entries: Vec<ClientDbListEntry>,
+    /// Total amount of database entries, only present if requested
+    pub count: Option<i32>,
+}
+
+ts_response! {
+    ClientDbInfo {
+        client_unique_identifier: String,
+        client_nickname: String,
+        client_database_id: i32,
+        client_created: i32,
+        client_lastconnected: i32,
+        client_totalconnections: i32,
+        client_flag_avatar: String,
+        client_description: String,
+        client_month_bytes_uploaded: u64,
+        client_month_bytes_downloaded: u64,
+        client_total_bytes_uploaded: u64,
+        client_total_bytes_downloaded: u64,
+        client_base64_hash_client_uid("client_base64HashClientUID"): String,
+        client_lastip: String
+    }
+}
+
+// client identity
+
+ts_response! {
+    ClientIdsEntry {
+        cluid: String,
+        clid: i32,
+        name: String
+    }
+}
+
+ts_response! {
+    ClientDbIdFromUid {
+        cluid: String,
+        cldbid: i32
+    }
+}
+
+ts_response! {
+    ClientNameEntry {
+        cluid: String,
+        cldbid: i32,
+        name: String
+    }
+}
+
+ts_response! {
+    ClientUidFromClid {
+        clid: i32,
+        cluid: String,
+        nickname: String
+    }
+}
+
+/// Any of the ways a client can be referred to
+#[derive(Debug, Clone, Copy)]
+pub enum ClientIdentifier<'a> {
+    ClientId(i32),
+    DatabaseId(i32),
+    Uid(&'a str),
+    Nickname(&'a str),
+}
+
+#[derive(Debug)]
+pub struct ClientIdentity {
+    pub database_id: i32,
+    pub uid: String,
+    pub nickname: String,
+    /// Ids of all connections of the client, empty if it is offline
+    pub client_ids: Vec<i32>,
+}
+
+// client actions
+
+ts_enum! {
+    KickReason {
+        Channel = 4,
+        Server = 5
+    }
+}
+
+// text messages
+
+ts_enum! {
+    TextMessageTarget {
+        Client = 1,
+        Channel = 2,
+        Server = 3
+    }
+}
+
+// offline messages
+
+ts_response! {
+    OfflineMessage {
+        msgid: i32,
+        cluid: String,
+        subject: String,
+        timestamp: i32,
+        flag_read: bool
+    }
+}
+
+ts_response! {
+    OfflineMessageContent {
+        msgid: i32,
+        cluid: String,
+        subject: String,
+        message: String,
+        timestamp: i32
+    }
+}
+
+// complaints
+
+ts_response! {
+    Complaint {
+        target_database_id("tcldbid"): i32,
+        target_name("tname"): String,
+        from_database_id("fcldbid"): i32,
+        from_name("fname"): String,
+        message: String,
+        timestamp: i32
+    }
+}
+
+// custom properties
+
+ts_response! {
+    CustomSearchEntry {
+        cldbid: i32,
+        ident: String,
+        value: String
+    }
+}
+
+// privilege keys
+
+ts_enum! {
+    TokenType {
+        ServerGroup = 0,
+        ChannelGroup = 1
+    }
+}
+
+ts_response! {
+    TokenEntry {
+        token: String,
+        token_type: TokenType,
+        token_id1: i32,
+        token_id2: i32,
+        token_created: i32,
+        token_description: String
+    }
+}
+
+// api keys
+
+ts_enum! {
+    ApiKeyScope {
+        Manage = "manage",
+        Write = "write",
+        Read = "read"
+    }
+}
+
+ts_response! {
+    ApiKey {
+        apikey: String,
+        id: i32,
+        sid: i32,
+        cldbid: i32,
+        scope: ApiKeyScope,
+        time_left: String
+    }
+}
+
+ts_response! {
+    ApiKeyEntry {
+        id: i32,
+        sid: i32,
+        cldbid: i32,
+        scope: ApiKeyScope,
+        time_left: String
+    }
+}
+
+#[derive(Debug)]
+pub struct ApiKeyList {
+    pub entries: Vec<ApiKeyEntry>,
+    /// Total amount of api keys, only present if requested
+    pub count: Option<i32>,
+}
+
+// query logins
+
+ts_response! {
+    QueryLogin {
+        cldbid: i32,
+        sid: i32,
+        client_login_name: String,
+        client_login_password: String
+    }
+}
+
+ts_response! {
+    QueryLoginEntry {
+        cldbid: i32,
+        sid: i32,
+        client_login_name: String
+    }
+}
+
+// virtual servers
+
+ts_response! {
+    ServerListEntry {
+        virtualserver_id: i32,
+        virtualserver_port: u16,
+        virtualserver_status: String,
+        virtualserver_clientsonline: Option<i32>,
+        virtualserver_queryclientsonline: Option<i32>,
+        virtualserver_maxclients: Option<i32>,
+        virtualserver_uptime: Option<i64>,
+        virtualserver_name: Option<String>,
+        virtualserver_autostart: Option<bool>,
+        virtualserver_machine_id: Option<String>,
+        virtualserver_unique_identifier: Option<String>
+    }
+}
+
+ts_response! {
+    ServerCreated {
+        sid: i32,
+        virtualserver_port: u16,
+        token: String
+    }
+}
+
+// server info
+
+ts_response! {
+    ServerInfo {
+        virtualserver_id: i32,
+        virtualserver_unique_identifier: String,
+        virtualserver_port: u16,
+        virtualserver_status: String,
+        virtualserver_platform: String,
+        virtualserver_version: String,
+        virtualserver_created: i64,
+        virtualserver_uptime: i64,
+        virtualserver_filebase: String,
+        virtualserver_flag_password: bool,
+        virtualserver_ask_for_privilegekey: bool,
+
+        virtualserver_clientsonline: i32,
+        virtualserver_queryclientsonline: i32,
+        virtualserver_channelsonline: i32,
+        virtualserver_client_connections: i64,
+        virtualserver_query_client_connections: i64,
+
+        // property fields
+        virtualserver_name: String,
+        virtualserver_name_phonetic: String,
+        virtualserver_nickname: Option<String>,
+        virtualserver_welcomemessage: String,
+        virtualserver_password: String,
+        virtualserver_machine_id: String,
+        virtualserver_autostart: bool,
+        virtualserver_maxclients: i32,
+        virtualserver_reserved_slots: i32,
+        virtualserver_hostmessage: String,
+        virtualserver_hostmessage_mode: i32,
+        virtualserver_default_server_group: i32,
+        virtualserver_default_channel_group: i32,
+        virtualserver_default_channel_admin_group: i32,
+        virtualserver_max_download_total_bandwidth: u64,
+        virtualserver_max_upload_total_bandwidth: u64,
+        virtualserver_download_quota: u64,
+        virtualserver_upload_quota: u64,
+        virtualserver_hostbanner_url: String,
+        virtualserver_hostbanner_gfx_url: String,
+        virtualserver_hostbanner_gfx_interval: i32,
+        virtualserver_hostbanner_mode: i32,
+        virtualserver_hostbutton_tooltip: String,
+        virtualserver_hostbutton_url: String,
+        virtualserver_hostbutton_gfx_url: String,
+        virtualserver_complain_autoban_count: i32,
+        virtualserver_complain_autoban_time: i32,
+        virtualserver_complain_remove_time: i32,
+        virtualserver_min_clients_in_channel_before_forced_silence: i32,
+        virtualserver_priority_speaker_dimm_modificator: f32,
+        virtualserver_antiflood_points_tick_reduce: i32,
+        virtualserver_antiflood_points_needed_command_block: i32,
+        virtualserver_antiflood_points_needed_ip_block: i32,
+        virtualserver_antiflood_points_needed_plugin_block: Option<i32>,
+        virtualserver_needed_identity_security_level: i32,
+        virtualserver_min_client_version: i64,
+        virtualserver_min_android_version: Option<i64>,
+        virtualserver_min_ios_version: Option<i64>,
+        virtualserver_log_client: bool,
+        virtualserver_log_query: bool,
+        virtualserver_log_channel: bool,
+        virtualserver_log_permissions: bool,
+        virtualserver_log_server: bool,
+        virtualserver_log_filetransfer: bool,
+        virtualserver_icon_id: i32,
+        virtualserver_weblist_enabled: bool,
+        virtualserver_codec_encryption_mode: i32,
+        virtualserver_channel_temp_delete_delay_default: i32,
+
+        // connection fields
+        virtualserver_month_bytes_uploaded: u64,
+        virtualserver_month_bytes_downloaded: u64,
+        virtualserver_total_bytes_uploaded: u64,
+        virtualserver_total_bytes_downloaded: u64,
+        virtualserver_total_ping: f64,
+        virtualserver_total_packetloss_speech: f64,
+        virtualserver_total_packetloss_keepalive: f64,
+        virtualserver_total_packetloss_control: f64,
+        virtualserver_total_packetloss_total: f64,
+        connection_filetransfer_bandwidth_sent: u64,
+        connection_filetransfer_bandwidth_received: u64,
+        connection_filetransfer_bytes_sent_total: u64,
+        connection_filetransfer_bytes_received_total: u64,
+        connection_packets_sent_total: u64,
+        connection_bytes_sent_total: u64,
+        connection_packets_received_total: u64,
+        connection_bytes_received_total: u64,
+        connection_bandwidth_sent_last_second_total: u64,
+        connection_bandwidth_sent_last_minute_total: u64,
+        connection_bandwidth_received_last_second_total: u64,
+        connection_bandwidth_received_last_minute_total: u64
+    }
+}
+
+// host and instance info
+
+ts_response! {
+    HostInfo {
+        instance_uptime: i64,
+        host_timestamp_utc: i64,
+        virtualservers_running_total: i32,
+        virtualservers_total_maxclients: i32,
+        virtualservers_total_clients_online: i32,
+        virtualservers_total_channels_online: i32,
+        connection_filetransfer_bandwidth_sent: u64,
+        connection_filetransfer_bandwidth_received: u64,
+        connection_filetransfer_bytes_sent_total: u64,
+        connection_filetransfer_bytes_received_total: u64,
+        connection_packets_sent_total: u64,
+        connection_bytes_sent_total: u64,
+        connection_packets_received_total: u64,
+        connection_bytes_received_total: u64,
+        connection_bandwidth_sent_last_second_total: u64,
+        connection_bandwidth_sent_last_minute_total: u64,
+        connection_bandwidth_received_last_second_total: u64,
+        connection_bandwidth_received_last_minute_total: u64
+    }
+}
+
+ts_response! {
+    InstanceInfo {
+        serverinstance_database_version: i32,
+        serverinstance_permissions_version: i32,
+
+        // property fields
+        serverinstance_guest_serverquery_group: i32,
+        serverinstance_template_serveradmin_group: i32,
+        serverinstance_template_serverdefault_group: i32,
+        serverinstance_template_channeladmin_group: i32,
+        serverinstance_template_channeldefault_group: i32,
+        serverinstance_filetransfer_port: u16,
+        serverinstance_max_download_total_bandwidth: u64,
+        serverinstance_max_upload_total_bandwidth: u64,
+        serverinstance_serverquery_flood_commands: i32,
+        serverinstance_serverquery_flood_time: i32,
+        serverinstance_serverquery_ban_time: i32,
+        serverinstance_serverquery_max_connections_per_ip: Option<i32>,
+        serverinstance_pending_connections_per_ip: i32
+    }
+}
+
+// snapshots
+
+ts_response! {
+    ServerSnapshot {
+        version: i32,
+        salt: Option<String>,
+        data: String
+    }
+}
+
+impl ServerSnapshot {
+    /// Encodes the snapshot in the same format the server uses
+    pub fn encode(&self) -> Result<String, QueryError> {
+        let mut buf = String::new();
+        let mut builder = CommandListBuilder::new(&mut buf);
+
+        builder.add("version", self.version)?;
+
+        if let Some(salt) = &self.salt {
+            builder.add_ref("salt", salt)?;
+        }
+
+        builder.add_ref("data", &self.data)?;
+
+        Ok(buf)
+    }
+
+    pub fn decode(buf: &str) -> Result<Self, QueryError> {
+        Self::from(&mut CommandResponse::decode(buf.trim(), false)?)
+    }
+
+    pub async fn write_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), QueryError> {
+        tokio::fs::write(path, self.encode()?).await
+            .map_err(QueryError::IoError)
+    }
+
+    pub async fn read_from_file<P: AsRef<Path>>(path: P) -> Result<Self, QueryError> {
+        let buf = tokio::fs::read_to_string(path).await
+            .map_err(QueryError::IoError)?;
+
+        Self::decode(&buf)
+    }
+}
+
+ts_response! {
+    SnapshotChannelMapping {
+        old_channel_id("ocid"): i32,
+        new_channel_id("ncid"): i32
+    }
+}
+
+// logs
+
+ts_enum! {
+    LogLevel {
+        Critical = 0,
+        Error = 1,
+        Warning = 2,
+        Debug = 3,
+        Info = 4,
+        Develop = 5
+    }
+}
+
+impl LogLevel {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "CRITICAL" => Some(LogLevel::Critical),
+            "ERROR" => Some(LogLevel::Error),
+            "WARNING" => Some(LogLevel::Warning),
+            "DEBUG" => Some(LogLevel::Debug),
+            "INFO" => Some(LogLevel::Info),
+            "DEVELOP" => Some(LogLevel::Develop),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub level: LogLevel,
+    pub channel: String,
+    /// The virtual server the entry belongs to, `None` for instance entries
+    pub sid: Option<i32>,
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Parses a line of the form `timestamp|level|channel|sid|message`, trimming the padded columns
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.splitn(5, '|');
+
+        let timestamp = parts.next()?.trim().to_owned();
+        let level = LogLevel::from_name(parts.next()?.trim())?;
+        let channel = parts.next()?.trim().to_owned();
+        let sid = match parts.next()?.trim() {
+            "" => None,
+            sid => Some(sid.parse().ok()?),
+        };
+        let message = parts.next()?.to_owned();
+
+        Some(Self {
+            timestamp,
+            level,
+            channel,
+            sid,
+            message,
+        })
+    }
+}
+
+impl Decode for LogEntry {
+    fn decode(key: &str, value: String) -> Result<Self, QueryError> {
+        Self::parse(&value).ok_or_else(|| QueryError::ArgTypeError {
+            key: key.to_string(),
+            value,
+            expected_type: "LogEntry".to_string(),
+            error: "Malformed log entry".to_string(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum LogLine {
+    Entry(LogEntry),
+    /// A line that doesn't follow the usual log format, kept as sent by the server
+    Raw(String),
+}
+
+impl Decode for LogLine {
+    fn decode(_key: &str, value: String) -> Result<Self, QueryError> {
+        match LogEntry::parse(&value) {
+            Some(entry) => Ok(LogLine::Entry(entry)),
+            None => Ok(LogLine::Raw(value)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LogView {
+    /// Position to continue reading from, 0 if the start of the log was reached
+    pub last_pos: i64,
+    pub file_size: i64,
+    pub entries: Vec<LogLine>,
+}
+
+// temporary passwords
+
+ts_response! {
+    TempPasswordEntry {
+        nickname: String,
+        uid: String,
+        description("desc"): String,
+        password("pw_clear"): String,
+        start: i64,
+        end: i64,
+        target_channel_id("tcid"): i32
+    }
+}
+
+// connection info
+
+ts_response! {
+    ServerConnectionInfo {
+        connection_filetransfer_bandwidth_sent: u64,
+        connection_filetransfer_bandwidth_received: u64,
+        connection_filetransfer_bytes_sent_total: u64,
+        connection_filetransfer_bytes_received_total: u64,
+        connection_packets_sent_total: u64,
+        connection_bytes_sent_total: u64,
+        connection_packets_received_total: u64,
+        connection_bytes_received_total: u64,
+        connection_bandwidth_sent_last_second_total: u64,
+        connection_bandwidth_sent_last_minute_total: u64,
+        connection_bandwidth_received_last_second_total: u64,
+        connection_bandwidth_received_last_minute_total: u64,
+        connection_connected_time: u64,
+        connection_packetloss_total: f64,
+        connection_ping: f64
+    }
+}
+
+// bindings
+
+ts_enum! {
+    BindingSubsystem {
+        Voice = "voice",
+        Query = "query",
+        FileTransfer = "filetransfer"
+    }
+}
+
+ts_response! {
+    BindingEntry {
+        ip: String
+    }
+}
+
+// search
+
+ts_response! {
+    ChannelFindEntry {
+        cid: i32,
+        channel_name: String
+    }
+}
+
+ts_response! {
+    ClientFindEntry {
+        clid: i32,
+        client_nickname: String
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let snapshot = ServerSnapshot {
+            version: 3,
+            salt: Some("c2FsdA==".to_string()),
+            data: "KLUv/WBsCd1XAB Q/x|y".to_string(),
+        };
+
+        let encoded = snapshot.encode().unwrap();
+
+        assert_eq!(encoded, "version=3 salt=c2FsdA== data=KLUv\\/WBsCd1XAB\\sQ\\/x\\py");
+
+        let decoded = ServerSnapshot::decode(&encoded).unwrap();
+
+        assert_eq!(decoded.version, 3);
+        assert_eq!(decoded.salt, snapshot.salt);
+        assert_eq!(decoded.data, snapshot.data);
+    }
+
+    #[test]
+    fn test_snapshot_without_salt() {
+        let decoded = ServerSnapshot::decode("version=2 data=abc\n").unwrap();
+
+        assert_eq!(decoded.version, 2);
+        assert_eq!(decoded.salt, None);
+        assert_eq!(decoded.data, "abc");
+    }
+
+    #[test]
+    fn test_decode_log_entry() {
+        let mut response = CommandResponse::decode("l=2023-11-20\\s18:04:15.421893|INFO\\s\\s\\s\\s|VirtualServer\\s|\\s\\s1|listening\\son\\s0.0.0.0:9987,\\s[::]:9987", false).unwrap();
+        let entry = response.get::<LogEntry>("l").unwrap();
+
+        assert_eq!(entry.timestamp, "2023-11-20 18:04:15.421893");
+        assert_eq!(entry.level, LogLevel::Info);
+        assert_eq!(entry.channel, "VirtualServer");
+        assert_eq!(entry.sid, Some(1));
+        assert_eq!(entry.message, "listening on 0.0.0.0:9987, [::]:9987");
+
+        let mut response = CommandResponse::decode("l=2023-11-20\\s18:04:14.999321|WARNING\\s|Accounting\\s\\s\\s\\s|\\s\\s\\s|Unable\\sto\\sopen\\slicensekey.dat\\p\\sfalling\\sback", false).unwrap();
+        let entry = response.get::<LogEntry>("l").unwrap();
+
+        assert_eq!(entry.level, LogLevel::Warning);
+        assert_eq!(entry.sid, None);
+        assert_eq!(entry.message, "Unable to open licensekey.dat| falling back");
+    }
+
+    #[test]
+    fn test_decode_log_line() {
+        let mut response = CommandResponse::decode("l=2023-11-20\\s18:04:15.421893|INFO\\s\\s\\s\\s|VirtualServer\\s|\\s\\s1|\\sindented\\smessage", false).unwrap();
+
+        match response.get::<LogLine>("l").unwrap() {
+            LogLine::Entry(entry) => assert_eq!(entry.message, " indented message"),
+            LogLine::Raw(line) => panic!("unexpected raw line {}", line),
+        }
+
+        let mut response = CommandResponse::decode("l=2023-11-20\\s18:04:15.421893|NOTICE\\s\\s|VirtualServer\\s|\\s\\s1|unknown\\slevel", false).unwrap();
+
+        match response.get::<LogLine>("l").unwrap() {
+            LogLine::Raw(line) => assert_eq!(line, "2023-11-20 18:04:15.421893|NOTICE  |VirtualServer |  1|unknown level"),
+            LogLine::Entry(entry) => panic!("unexpected entry {:?}", entry),
+        }
+
+        let mut response = CommandResponse::decode("l=continued\\sfrom\\sprevious\\sline", false).unwrap();
+
+        assert!(matches!(response.get::<LogLine>("l").unwrap(), LogLine::Raw(_)));
+    }
 }
\ No newline at end of file